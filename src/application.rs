@@ -1,4 +1,7 @@
-use crate::commands::{aliases, indices, mappings, reindex};
+use crate::commands::{
+    aliases, api, cluster, count, docs, export, histogram, ilm, import, indices, mappings, migrate,
+    nodes, reindex, search, shards, stats, tasks, templates, terms,
+};
 use crate::commands_enum;
 use crate::config::Cluster;
 use crate::config::Config;
@@ -31,7 +34,10 @@ pub struct ApplicationArguments {
 
 // Generates the commands based on the modules in the commands directory
 // Specify the modules you want to include in the commands_enum! macro
-commands_enum!(indices, aliases, mappings, reindex);
+commands_enum!(
+    cluster, nodes, shards, indices, aliases, docs, search, count, terms, histogram, stats, export,
+    import, api, mappings, templates, ilm, reindex, migrate, tasks
+);
 
 #[derive(Debug)]
 pub struct Application {
@@ -67,6 +73,11 @@ impl Application {
         Ok(client)
     }
 
+    /// Name of the cluster given on the command line
+    pub fn cluster_name(&self) -> &str {
+        &self.args.cluster
+    }
+
     fn get_current_cluster(&self) -> Result<&Cluster> {
         self.config.get_cluster_by_name(&self.args.cluster)
     }
@@ -4,8 +4,10 @@ use clap::{Parser, Subcommand};
 use crate::application::Application;
 
 use super::aliases_add;
+use super::aliases_apply;
 use super::aliases_list;
 use super::aliases_remove;
+use super::aliases_swap;
 use super::aliases_update;
 
 #[derive(Debug, Parser)]
@@ -24,6 +26,10 @@ enum Commands {
     Remove(aliases_remove::Arguments),
     /// Updates aliases for an index
     Update(aliases_update::Arguments),
+    /// Applies a list of alias actions from a file in one atomic request
+    Apply(aliases_apply::Arguments),
+    /// Points an alias exclusively to an index
+    Swap(aliases_swap::Arguments),
 }
 
 pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
@@ -32,5 +38,7 @@ pub async fn handle_command(args: &Arguments, application: &Application) -> Resu
         Commands::Add(args) => aliases_add::handle_command(args, application).await,
         Commands::Remove(args) => aliases_remove::handle_command(args, application).await,
         Commands::Update(args) => aliases_update::handle_command(args, application).await,
+        Commands::Apply(args) => aliases_apply::handle_command(args, application).await,
+        Commands::Swap(args) => aliases_swap::handle_command(args, application).await,
     }
 }
@@ -1,18 +1,37 @@
 use crate::{
     application::Application,
-    utils::{handle_response::handle_response, output::Output},
+    utils::{handle_response::handle_response, input::read_json_input, output::Output},
 };
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use clap::Parser;
 use colored::Colorize;
-use serde_json::json;
+use serde_json::{json, Value};
 
 #[derive(Debug, Parser)]
 pub struct Arguments {
     /// Alias
     alias: String,
-    /// Index you want to add to alias
-    index: String,
+    /// Indices you want to add to alias (supports wildcards)
+    #[arg(required = true)]
+    indices: Vec<String>,
+    /// Query filtering the documents visible through the alias, inline JSON or path to a JSON file
+    #[arg(short, long)]
+    filter: Option<String>,
+    /// Routing value used for both indexing and search operations
+    #[arg(short, long)]
+    routing: Option<String>,
+    /// Routing value used for indexing operations
+    #[arg(long)]
+    index_routing: Option<String>,
+    /// Routing value used for search operations
+    #[arg(long)]
+    search_routing: Option<String>,
+    /// Makes the index the write index of the alias (`--is-write-index=false` unsets it)
+    #[arg(long, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    is_write_index: Option<bool>,
+    /// Hides the alias from wildcard expressions (`--is-hidden=false` unsets it)
+    #[arg(long, num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    is_hidden: Option<bool>,
     /// Output format
     #[arg(short, long, value_enum, default_value_t = Output::Default)]
     output: Output,
@@ -22,35 +41,46 @@ pub struct Arguments {
 }
 
 pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    // An alias has a single write index
+    let single_index = matches!(args.indices.as_slice(), [index] if !index.contains('*'));
+    if args.is_write_index == Some(true) && !single_index {
+        bail!("--is-write-index requires a single index without wildcards");
+    }
+
     let client = application.get_http_client()?;
+
+    let filter = match &args.filter {
+        Some(filter) => Some(read_json_input(filter, "alias filter")?),
+        None => None,
+    };
+
+    let actions: Vec<Value> = args
+        .indices
+        .iter()
+        .map(|index| json!({ "add": build_add_action(args, index, &filter) }))
+        .collect();
+
     let response = client
         .indices()
         .update_aliases()
-        .body(json!({
-            "actions": [
-                {
-                    "add": {
-                        "index": args.index,
-                        "alias": args.alias
-                    }
-                }
-            ]
-        }))
+        .body(json!({ "actions": actions }))
         .send()
         .await
         .context("Request error for adding alias")?;
 
+    let indices = args.indices.join(", ");
+
     handle_response(
         &args.output,
         response,
         format!(
             "Index {} added to alias {} successfully!",
-            args.index.bold(),
+            indices.bold(),
             args.alias.bold()
         ),
         format!(
             "Index {} cannot be added to alias {}",
-            args.index.bold(),
+            indices.bold(),
             args.alias.bold()
         ),
         args.pretty,
@@ -59,3 +89,31 @@ pub async fn handle_command(args: &Arguments, application: &Application) -> Resu
 
     Ok(())
 }
+
+fn build_add_action(args: &Arguments, index: &str, filter: &Option<Value>) -> Value {
+    let mut action = json!({
+        "index": index,
+        "alias": args.alias
+    });
+
+    if let Some(filter) = filter {
+        action["filter"] = filter.clone();
+    }
+    if let Some(routing) = &args.routing {
+        action["routing"] = json!(routing);
+    }
+    if let Some(index_routing) = &args.index_routing {
+        action["index_routing"] = json!(index_routing);
+    }
+    if let Some(search_routing) = &args.search_routing {
+        action["search_routing"] = json!(search_routing);
+    }
+    if let Some(is_write_index) = args.is_write_index {
+        action["is_write_index"] = json!(is_write_index);
+    }
+    if let Some(is_hidden) = args.is_hidden {
+        action["is_hidden"] = json!(is_hidden);
+    }
+
+    action
+}
@@ -0,0 +1,383 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::indices::IndicesGetAliasParts;
+use elasticsearch::params::ExpandWildcards;
+use elasticsearch::Elasticsearch;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use tabled::{Table, Tabled};
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_response;
+use crate::utils::input::{check_confirmable_input, confirm, read_text_input};
+use crate::utils::output::{output_json, output_yaml, print_table, Output};
+use crate::utils::pattern::matches_patterns;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Path to a YAML (or JSON) file containing a list of add, remove and remove_index actions
+    #[arg(short, long)]
+    file: String,
+    /// Only displays the plan without applying it
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+    /// Applies the actions without asking for confirmation
+    #[arg(short, long, default_value_t = false)]
+    yes: bool,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+/// Actions file, either a list of actions or an object with an `actions` list
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ActionsFile {
+    Wrapped { actions: Vec<AliasAction> },
+    List(Vec<AliasAction>),
+}
+
+/// Same format as the actions of the `_aliases` API
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum AliasAction {
+    Add(ActionTarget),
+    Remove(ActionTarget),
+    RemoveIndex(ActionTarget),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct ActionTarget {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    index: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    indices: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    alias: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    aliases: Vec<String>,
+    /// Filter, routing, is_write_index...
+    #[serde(flatten)]
+    options: Map<String, Value>,
+}
+
+impl ActionTarget {
+    fn indices(&self) -> Vec<&str> {
+        self.index
+            .iter()
+            .chain(self.indices.iter())
+            .map(String::as_str)
+            .collect()
+    }
+
+    fn aliases(&self) -> Vec<&str> {
+        self.alias
+            .iter()
+            .chain(self.aliases.iter())
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct PlannedChange {
+    #[tabled(display_with = "display_change")]
+    change: &'static str,
+    alias: String,
+    index: String,
+    details: String,
+}
+
+/// Aliases definitions by index
+type AliasesState = BTreeMap<String, Map<String, Value>>;
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    if !args.dry_run {
+        check_confirmable_input(&args.file, "--file", args.yes)?;
+    }
+
+    let content = read_text_input(&args.file, "alias actions")?;
+    let actions = match serde_yaml::from_str(&content)
+        .context(format!("Malformated alias actions in {}", args.file))?
+    {
+        ActionsFile::Wrapped { actions } => actions,
+        ActionsFile::List(actions) => actions,
+    };
+
+    if actions.is_empty() {
+        bail!("No alias actions found in {}", args.file);
+    }
+
+    let client = application.get_http_client()?;
+    let state = get_aliases_state(&client).await?;
+    let plan = build_plan(&actions, &state)?;
+
+    match args.output {
+        Output::Default => {
+            print_table(Table::new(&plan), "Plan");
+        }
+        Output::Json if args.dry_run => output_json(&plan, args.pretty)?,
+        Output::Yaml if args.dry_run => output_yaml(&plan)?,
+        Output::Json | Output::Yaml => {}
+    }
+
+    if args.dry_run {
+        return Ok(());
+    }
+
+    if !args.yes && !confirm(&format!("Apply {} alias actions?", actions.len()))? {
+        bail!("Alias actions not applied");
+    }
+
+    let response = client
+        .indices()
+        .update_aliases()
+        .body(json!({ "actions": actions }))
+        .send()
+        .await
+        .context("Request error for applying alias actions")?;
+
+    handle_response(
+        &args.output,
+        response,
+        format!("{} alias actions applied successfully!", actions.len()),
+        "Alias actions cannot be applied".to_string(),
+        args.pretty,
+    )
+    .await
+}
+
+async fn get_aliases_state(client: &Elasticsearch) -> Result<AliasesState> {
+    let response = client
+        .indices()
+        .get_alias(IndicesGetAliasParts::None)
+        .expand_wildcards(&[ExpandWildcards::All])
+        .send()
+        .await
+        .context("Request error for getting aliases")?;
+
+    if !response.status_code().is_success() {
+        bail!("Cannot get current aliases");
+    }
+
+    let response_body: Map<String, Value> = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for aliases")?;
+
+    Ok(response_body
+        .into_iter()
+        .map(|(index, definition)| {
+            let aliases = match definition.get("aliases") {
+                Some(Value::Object(aliases)) => aliases.clone(),
+                _ => Map::new(),
+            };
+            (index, aliases)
+        })
+        .collect())
+}
+
+/// Compares the actions with the current aliases, every referenced index must exist
+fn build_plan(actions: &[AliasAction], state: &AliasesState) -> Result<Vec<PlannedChange>> {
+    let mut plan = Vec::new();
+    let mut unknown_indices = Vec::new();
+
+    for action in actions {
+        let target = match action {
+            AliasAction::Add(target)
+            | AliasAction::Remove(target)
+            | AliasAction::RemoveIndex(target) => target,
+        };
+
+        let target_indices = target.indices();
+        if target_indices.is_empty() {
+            bail!("Missing index in alias action {:?}", action);
+        }
+
+        let mut indices = Vec::new();
+        for pattern in target_indices {
+            let matching: Vec<&String> = state
+                .keys()
+                .filter(|index| matches_patterns(pattern, index))
+                .collect();
+
+            match matching.is_empty() {
+                true => unknown_indices.push(pattern.to_string()),
+                false => indices.extend(matching),
+            }
+        }
+
+        if let AliasAction::RemoveIndex(_) = action {
+            for index in indices {
+                let aliases: Vec<&String> = state[index].keys().collect();
+                plan.push(PlannedChange {
+                    change: "delete index",
+                    alias: "-".to_string(),
+                    index: index.to_owned(),
+                    details: match aliases.is_empty() {
+                        true => "".to_string(),
+                        false => format!(
+                            "removes aliases {}",
+                            aliases
+                                .iter()
+                                .map(|alias| alias.as_str())
+                                .collect::<Vec<&str>>()
+                                .join(", ")
+                        ),
+                    },
+                });
+            }
+            continue;
+        }
+
+        let aliases = target.aliases();
+        if aliases.is_empty() {
+            bail!("Missing alias in alias action {:?}", action);
+        }
+
+        for index in indices {
+            let current_aliases = &state[index];
+            for alias in &aliases {
+                let exists = current_aliases.keys().any(|a| matches_patterns(alias, a));
+
+                let (change, details) = match (action, exists) {
+                    (AliasAction::Add(_), false) => ("add", options_details(target)),
+                    (AliasAction::Add(_), true) if target.options.is_empty() => {
+                        ("unchanged", "already exists".to_string())
+                    }
+                    (AliasAction::Add(_), true) => ("update", options_details(target)),
+                    (_, true) => ("remove", "".to_string()),
+                    (_, false) => ("not found", "alias is not set".to_string()),
+                };
+
+                plan.push(PlannedChange {
+                    change,
+                    alias: alias.to_string(),
+                    index: index.to_owned(),
+                    details,
+                });
+            }
+        }
+    }
+
+    if !unknown_indices.is_empty() {
+        bail!(
+            "Alias actions reference unknown indices: {}",
+            unknown_indices.join(", ")
+        );
+    }
+
+    Ok(plan)
+}
+
+fn options_details(target: &ActionTarget) -> String {
+    match target.options.is_empty() {
+        true => "".to_string(),
+        false => Value::Object(target.options.clone()).to_string(),
+    }
+}
+
+fn display_change(change: &&str) -> String {
+    match *change {
+        "add" => "+ add".green(),
+        "update" => "~ update".yellow(),
+        "remove" => "- remove".red(),
+        "delete index" => "x delete index".red(),
+        "not found" => "! not found".yellow(),
+        change => format!("= {}", change).normal(),
+    }
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> AliasesState {
+        serde_json::from_value(json!({
+            "logs-1": { "logs": {} },
+            "logs-2": {},
+            "metrics": { "metrics-read": {}, "metrics-write": {} }
+        }))
+        .unwrap()
+    }
+
+    fn plan(actions: Value) -> Result<Vec<PlannedChange>> {
+        let actions: Vec<AliasAction> = serde_json::from_value(actions).unwrap();
+        build_plan(&actions, &state())
+    }
+
+    fn changes(plan: &[PlannedChange]) -> Vec<(&str, &str, &str)> {
+        plan.iter()
+            .map(|change| (change.change, change.alias.as_str(), change.index.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn build_plan_compares_with_current_aliases() {
+        let plan = plan(json!([
+            { "add": { "index": "logs-2", "alias": "logs" } },
+            { "add": { "index": "logs-1", "alias": "logs" } },
+            { "add": { "index": "logs-1", "alias": "logs", "is_write_index": true } },
+            { "remove": { "index": "logs-1", "alias": "logs" } },
+            { "remove": { "index": "logs-2", "alias": "logs" } }
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            changes(&plan),
+            vec![
+                ("add", "logs", "logs-2"),
+                ("unchanged", "logs", "logs-1"),
+                ("update", "logs", "logs-1"),
+                ("remove", "logs", "logs-1"),
+                ("not found", "logs", "logs-2"),
+            ]
+        );
+        assert_eq!(plan[2].details, r#"{"is_write_index":true}"#);
+    }
+
+    #[test]
+    fn build_plan_expands_index_patterns() {
+        let plan = plan(json!([{ "add": { "index": "logs-*", "aliases": ["all"] } }])).unwrap();
+        assert_eq!(
+            changes(&plan),
+            vec![("add", "all", "logs-1"), ("add", "all", "logs-2")]
+        );
+    }
+
+    #[test]
+    fn build_plan_lists_aliases_removed_with_an_index() {
+        let plan = plan(json!([{ "remove_index": { "index": "metrics" } }])).unwrap();
+        assert_eq!(changes(&plan), vec![("delete index", "-", "metrics")]);
+        assert_eq!(
+            plan[0].details,
+            "removes aliases metrics-read, metrics-write"
+        );
+    }
+
+    #[test]
+    fn build_plan_rejects_unknown_indices() {
+        let err = plan(json!([
+            { "add": { "indices": ["logs-1", "missing"], "alias": "logs" } },
+            { "remove_index": { "index": "traces-*" } }
+        ]))
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Alias actions reference unknown indices: missing, traces-*"
+        );
+    }
+
+    #[test]
+    fn build_plan_rejects_incomplete_actions() {
+        assert!(plan(json!([{ "add": { "alias": "logs" } }])).is_err());
+        assert!(plan(json!([{ "remove": { "index": "logs-1" } }])).is_err());
+    }
+}
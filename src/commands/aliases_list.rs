@@ -1,14 +1,25 @@
-use anyhow::{Context, Result};
-use clap::Parser;
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, ValueEnum};
 use colored::Colorize;
 use elasticsearch::cat::CatAliasesParts;
+use elasticsearch::http::StatusCode;
+use elasticsearch::indices::IndicesGetAliasParts;
+use elasticsearch::Elasticsearch;
 use serde::{Deserialize, Serialize};
-use tabled::settings::object::Rows;
-use tabled::settings::{Format, Modify, Panel, Style};
+use serde_json::{Map, Value};
+use tabled::builder::Builder;
+use tabled::settings::locator::ByColumnName;
+use tabled::settings::Disable;
 use tabled::{Table, Tabled};
 
 use crate::application::Application;
-use crate::utils::output::{output_json, Output};
+use crate::utils::output::{output_json, output_yaml, print_table, Output};
+use crate::utils::pattern::matches_patterns;
+
+/// Columns hidden when no alias uses them
+const OPTIONAL_COLUMNS: [&str; 3] = ["filter", "routing_index", "routing_search"];
 
 #[derive(Debug, Deserialize, Serialize, Tabled)]
 pub struct Alias {
@@ -19,10 +30,52 @@ pub struct Alias {
     routing_index: String,
     #[serde(rename = "routing.search")]
     routing_search: String,
+    is_write_index: String,
+}
+
+impl Alias {
+    fn column_value(&self, column: &str) -> &str {
+        match column {
+            "filter" => &self.filter,
+            "routing_index" => &self.routing_index,
+            "routing_search" => &self.routing_search,
+            _ => "",
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum GroupBy {
+    /// One row per alias with all its indices
+    Alias,
+    /// One row per index with all its aliases
+    Index,
+}
+
+#[derive(Debug, Serialize)]
+struct GroupMember {
+    name: String,
+    is_write_index: bool,
 }
 
 #[derive(Parser, Debug)]
 pub struct Arguments {
+    /// Only lists aliases matching these patterns (comma separated, supports wildcards)
+    #[arg(short, long)]
+    alias: Option<String>,
+
+    /// Only lists aliases of indices matching these patterns (comma separated, supports wildcards)
+    #[arg(short, long)]
+    index: Option<String>,
+
+    /// Groups aliases by alias or by index
+    #[arg(short, long, value_enum)]
+    group_by: Option<GroupBy>,
+
+    /// Displays the filters definitions instead of `*`
+    #[arg(short = 'f', long, default_value_t = false)]
+    show_filters: bool,
+
     /// Output format
     #[arg(short, long, value_enum, default_value_t = Output::Default)]
     output: Output,
@@ -34,41 +87,240 @@ pub struct Arguments {
 
 pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
     let client = application.get_http_client()?;
+
+    let aliases = match args.show_filters {
+        true => get_aliases_with_filters(&client, args).await?,
+        false => get_cat_aliases(&client, args).await?,
+    };
+
+    match args.group_by {
+        Some(group_by) => output_groups(args, group_by, &aliases),
+        None => output_aliases(args, aliases),
+    }
+}
+
+async fn get_cat_aliases(client: &Elasticsearch, args: &Arguments) -> Result<Vec<Alias>> {
+    let alias_patterns: Vec<&str> = match &args.alias {
+        Some(patterns) => patterns.split(',').collect(),
+        None => vec![],
+    };
+
     let cat = client.cat();
+    let parts = match alias_patterns.is_empty() {
+        true => CatAliasesParts::None,
+        false => CatAliasesParts::Name(&alias_patterns),
+    };
 
     let response = cat
-        .aliases(CatAliasesParts::None)
+        .aliases(parts)
         .h(&[
             "alias",
             "index",
             "filter",
             "routing.index",
             "routing.search",
+            "is_write_index",
         ])
         .format("json")
         .send()
         .await
         .context("Request error for getting aliases list")?;
 
-    let aliases: Vec<Alias> = response
+    let mut aliases: Vec<Alias> = response
         .json()
         .await
         .context("Cannot parse JSON response for aliases list")?;
 
+    // _cat/aliases cannot filter by index
+    if let Some(index_patterns) = &args.index {
+        aliases.retain(|alias| matches_patterns(index_patterns, &alias.index));
+    }
+
+    Ok(aliases)
+}
+
+/// Uses the `_alias` API which returns the filters definitions
+async fn get_aliases_with_filters(client: &Elasticsearch, args: &Arguments) -> Result<Vec<Alias>> {
+    let alias_patterns: Vec<&str> = args.alias.iter().flat_map(|p| p.split(',')).collect();
+    let index_patterns: Vec<&str> = args.index.iter().flat_map(|p| p.split(',')).collect();
+
+    let parts = match (index_patterns.is_empty(), alias_patterns.is_empty()) {
+        (true, true) => IndicesGetAliasParts::None,
+        (true, false) => IndicesGetAliasParts::Name(&alias_patterns),
+        (false, true) => IndicesGetAliasParts::Index(&index_patterns),
+        (false, false) => IndicesGetAliasParts::IndexName(&index_patterns, &alias_patterns),
+    };
+
+    let response = client
+        .indices()
+        .get_alias(parts)
+        .send()
+        .await
+        .context("Request error for getting aliases list")?;
+
+    // Elasticsearch answers with a 404 when no alias matches
+    if response.status_code() == StatusCode::NOT_FOUND {
+        return Ok(vec![]);
+    }
+
+    if !response.status_code().is_success() {
+        bail!("Cannot get aliases list");
+    }
+
+    let response_body: Map<String, Value> = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for aliases list")?;
+
+    let display = |value: Option<&Value>| match value {
+        Some(Value::String(s)) => s.to_owned(),
+        Some(value) => value.to_string(),
+        None => "-".to_string(),
+    };
+
+    let mut aliases = Vec::new();
+    for (index, index_aliases) in &response_body {
+        let index_aliases = match index_aliases["aliases"].as_object() {
+            Some(index_aliases) => index_aliases,
+            None => continue,
+        };
+
+        for (alias, definition) in index_aliases {
+            aliases.push(Alias {
+                alias: alias.to_owned(),
+                index: index.to_owned(),
+                filter: display(definition.get("filter")),
+                routing_index: display(definition.get("index_routing")),
+                routing_search: display(definition.get("search_routing")),
+                is_write_index: display(definition.get("is_write_index")),
+            });
+        }
+    }
+
+    aliases.sort_by(|a, b| (&a.alias, &a.index).cmp(&(&b.alias, &b.index)));
+
+    Ok(aliases)
+}
+
+fn output_aliases(args: &Arguments, aliases: Vec<Alias>) -> Result<()> {
     match args.output {
         Output::Default => {
-            let header_format = Format::content(|s| s.bold().to_string());
+            let empty_columns: Vec<&str> = OPTIONAL_COLUMNS
+                .into_iter()
+                .filter(|column| {
+                    aliases
+                        .iter()
+                        .all(|alias| alias.column_value(column) == "-")
+                })
+                .collect();
 
             let mut table = Table::new(aliases);
-            table
-                .with(Style::modern())
-                .with(Panel::header("Aliases".bold().to_string()))
-                .with(Modify::new(Rows::single(1)).with(header_format));
+            for column in empty_columns {
+                table.with(Disable::column(ByColumnName::new(column)));
+            }
 
-            println!("{table}");
+            print_table(table, "Aliases");
         }
         Output::Json => output_json(&aliases, args.pretty)?,
+        Output::Yaml => output_yaml(&aliases)?,
     };
 
     Ok(())
 }
+
+fn output_groups(args: &Arguments, group_by: GroupBy, aliases: &[Alias]) -> Result<()> {
+    let mut groups: BTreeMap<&str, Vec<GroupMember>> = BTreeMap::new();
+    for alias in aliases {
+        let (key, member) = match group_by {
+            GroupBy::Alias => (&alias.alias, &alias.index),
+            GroupBy::Index => (&alias.index, &alias.alias),
+        };
+
+        groups.entry(key).or_default().push(GroupMember {
+            name: member.to_owned(),
+            is_write_index: alias.is_write_index == "true",
+        });
+    }
+
+    match args.output {
+        Output::Default => {
+            let (title, header) = match group_by {
+                GroupBy::Alias => ("Aliases", ["alias", "indices"]),
+                GroupBy::Index => ("Indices", ["index", "aliases"]),
+            };
+
+            let mut builder = Builder::default();
+            builder.set_header(header);
+            for (key, members) in &groups {
+                let members = members
+                    .iter()
+                    .map(|member| match member.is_write_index {
+                        true => format!("{} {}", member.name, "(write)".bold()),
+                        false => member.name.to_owned(),
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                builder.push_record([key.to_string(), members]);
+            }
+
+            print_table(builder.build(), title);
+        }
+        Output::Json => output_json(&groups, args.pretty)?,
+        Output::Yaml => output_yaml(&groups)?,
+    };
+
+    Ok(())
+}
+
+/// Returns the indices an alias currently points to
+pub async fn get_alias_indices(client: &Elasticsearch, alias: &str) -> Result<Vec<String>> {
+    let definitions = get_alias_definitions(client, alias).await?;
+    if definitions.is_empty() {
+        bail!("Alias {} does not exist", alias);
+    }
+
+    Ok(definitions.into_keys().collect())
+}
+
+/// Returns the options (filter, routing, is_write_index...) of an alias by index, nothing when
+/// the alias does not exist
+pub async fn get_alias_definitions(
+    client: &Elasticsearch,
+    alias: &str,
+) -> Result<BTreeMap<String, Map<String, Value>>> {
+    let response = client
+        .indices()
+        .get_alias(IndicesGetAliasParts::Name(&[alias]))
+        .send()
+        .await
+        .context(format!(
+            "Request error for getting indices of alias {}",
+            alias
+        ))?;
+
+    if response.status_code() == StatusCode::NOT_FOUND {
+        return Ok(BTreeMap::new());
+    }
+
+    if !response.status_code().is_success() {
+        bail!("Cannot get indices of alias {}", alias);
+    }
+
+    let response_body: Map<String, Value> = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for alias indices")?;
+
+    let definitions = response_body
+        .into_iter()
+        .map(|(index, mut value)| {
+            let options = match value["aliases"][alias].take() {
+                Value::Object(options) => options,
+                _ => Map::new(),
+            };
+            (index, options)
+        })
+        .collect();
+
+    Ok(definitions)
+}
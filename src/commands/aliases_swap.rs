@@ -0,0 +1,168 @@
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::http::response::Response;
+use elasticsearch::Elasticsearch;
+use serde_json::{json, Map, Value};
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{output_json, output_yaml, print_success, Output};
+
+use super::aliases_list::get_alias_definitions;
+use super::indices_delete::delete_indices;
+
+/// The alias is created if missing, its filter and routing are kept
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Alias to move (no wildcards)
+    alias: String,
+    /// Index the alias will exclusively point to
+    new_index: String,
+    /// Deletes the indices the alias pointed to before the swap
+    #[arg(long, default_value_t = false)]
+    delete_old: bool,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    check_alias_name(&args.alias)?;
+
+    let client = application.get_http_client()?;
+
+    let definitions = get_alias_definitions(&client, &args.alias).await?;
+    let old_indices: Vec<String> = definitions
+        .keys()
+        .filter(|index| *index != &args.new_index)
+        .cloned()
+        .collect();
+    let options = inherited_options(&definitions, &args.new_index);
+
+    let response = swap_alias(
+        &client,
+        &args.alias,
+        &old_indices,
+        &args.new_index,
+        &options,
+    )
+    .await?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            format!(
+                "Alias {} cannot be swapped to {}",
+                args.alias.bold(),
+                args.new_index.bold()
+            ),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let delete_old = args.delete_old && !old_indices.is_empty();
+    if delete_old {
+        delete_indices(&client, &old_indices).await?;
+    }
+
+    match args.output {
+        Output::Default => {
+            print_success(format!(
+                "Alias {} now points to {}!",
+                args.alias.bold(),
+                args.new_index.bold()
+            ));
+
+            if !old_indices.is_empty() {
+                let action = match delete_old {
+                    true => "Deleted",
+                    false => "Removed from",
+                };
+                println!("{} {}", action, old_indices.join(", "));
+            }
+        }
+        Output::Json => output_json(&swap_summary(args, &old_indices, delete_old), args.pretty)?,
+        Output::Yaml => output_yaml(&swap_summary(args, &old_indices, delete_old))?,
+    }
+
+    Ok(())
+}
+
+fn swap_summary(args: &Arguments, old_indices: &[String], delete_old: bool) -> Value {
+    json!({
+        "alias": args.alias,
+        "index": args.new_index,
+        "removed_from": old_indices,
+        "deleted": delete_old
+    })
+}
+
+/// Swapping a wildcard alias would remove every matching alias
+pub fn check_alias_name(alias: &str) -> Result<()> {
+    if alias.contains(['*', ',']) {
+        bail!("Alias {} must be a single name without wildcards", alias);
+    }
+
+    Ok(())
+}
+
+/// Options of the alias to keep on the new index: the ones of the write index, or else of the
+/// first index the alias points to
+pub fn inherited_options(
+    definitions: &BTreeMap<String, Map<String, Value>>,
+    new_index: &str,
+) -> Map<String, Value> {
+    let mut old_definitions = definitions
+        .iter()
+        .filter(|(index, _)| *index != new_index)
+        .map(|(_, options)| options);
+
+    let write_index = old_definitions
+        .clone()
+        .find(|options| options.get("is_write_index") == Some(&Value::Bool(true)));
+
+    write_index
+        .or_else(|| old_definitions.next())
+        .or_else(|| definitions.get(new_index))
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Removes the alias from the old indices and adds it to the new index in one atomic request
+pub async fn swap_alias(
+    client: &Elasticsearch,
+    alias: &str,
+    old_indices: &[String],
+    new_index: &str,
+    options: &Map<String, Value>,
+) -> Result<Response> {
+    check_alias_name(alias)?;
+
+    let mut actions: Vec<Value> = old_indices
+        .iter()
+        .map(|index| json!({ "remove": { "index": index, "alias": alias } }))
+        .collect();
+
+    let mut add = options.clone();
+    add.insert("index".to_string(), json!(new_index));
+    add.insert("alias".to_string(), json!(alias));
+    actions.push(json!({ "add": add }));
+
+    let response = client
+        .indices()
+        .update_aliases()
+        .body(json!({ "actions": actions }))
+        .send()
+        .await
+        .context(format!("Request error for swapping alias {}", alias))?;
+
+    Ok(response)
+}
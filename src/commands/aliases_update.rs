@@ -56,7 +56,7 @@ pub async fn handle_command(args: &Arguments, application: &Application) -> Resu
             args.index.bold(),
             args.new_alias.bold(),
         ),
-        format!("Index aliases cannot be updated",),
+        "Index aliases cannot be updated".to_string(),
         args.pretty,
     )
     .await?;
@@ -0,0 +1,142 @@
+use anyhow::{bail, Context, Result};
+use clap::{Parser, ValueEnum};
+use elasticsearch::http::headers::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use elasticsearch::http::Method;
+use serde_json::Value;
+
+use crate::application::Application;
+use crate::utils::input::read_text_input;
+use crate::utils::output::{output_json, output_yaml, print_success, Output};
+
+/// Sends any request to the cluster, exits with a non-zero code on 4xx and 5xx responses
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// HTTP method
+    #[arg(value_enum, ignore_case = true)]
+    method: HttpMethod,
+    /// Path of the API, optionally with a query string (e.g. /_cluster/settings)
+    path: String,
+    /// Request body, inline JSON or path to a file ("-" reads from stdin)
+    #[arg(short, long)]
+    body: Option<String>,
+    /// Query string parameter as key=value, can be repeated
+    #[arg(long = "param", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "UPPER")]
+enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Delete,
+    Head,
+}
+
+impl HttpMethod {
+    fn to_method(self) -> Method {
+        match self {
+            HttpMethod::Get => Method::Get,
+            HttpMethod::Post => Method::Post,
+            HttpMethod::Put => Method::Put,
+            HttpMethod::Delete => Method::Delete,
+            HttpMethod::Head => Method::Head,
+        }
+    }
+}
+
+fn parse_param(param: &str) -> Result<(String, String), String> {
+    match param.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("`{}` must be formatted as key=value", param)),
+    }
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+
+    let (path, query_string) = match args.path.split_once('?') {
+        Some((path, query_string)) => (path, query_string),
+        None => (args.path.as_str(), ""),
+    };
+    let path = match path.starts_with('/') {
+        true => path.to_string(),
+        false => format!("/{}", path),
+    };
+
+    let mut params: Vec<(String, String)> = query_string
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .map(|param| match param.split_once('=') {
+            Some((key, value)) => (key.to_string(), value.to_string()),
+            None => (param.to_string(), "".to_string()),
+        })
+        .collect();
+    params.extend(args.params.iter().cloned());
+
+    let body = match &args.body {
+        Some(body) if body.starts_with('{') || body.starts_with('[') => Some(body.to_owned()),
+        Some(path) => Some(read_text_input(path, "request body")?),
+        None => None,
+    };
+
+    // Bulk-like APIs expect newline delimited JSON
+    let mut headers = HeaderMap::new();
+    if path.ends_with("_bulk") || path.ends_with("_msearch") || path.ends_with("_msearch/template")
+    {
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/x-ndjson"),
+        );
+    }
+
+    let query_string = match params.is_empty() {
+        true => None,
+        false => Some(params.as_slice()),
+    };
+
+    let response = client
+        .send(
+            args.method.to_method(),
+            &path,
+            headers,
+            query_string,
+            body,
+            None,
+        )
+        .await
+        .context(format!("Request error for {:?} {}", args.method, args.path))?;
+
+    let status_code = response.status_code();
+    let text = response.text().await.context("Cannot read response body")?;
+
+    match serde_json::from_str::<Value>(&text) {
+        Ok(json) => match args.output {
+            Output::Default => output_json(&json, true)?,
+            Output::Json => output_json(&json, args.pretty)?,
+            Output::Yaml => output_yaml(&json)?,
+        },
+        Err(_) if text.is_empty() => {
+            if let Output::Default = args.output {
+                if status_code.is_success() {
+                    print_success(status_code.to_string());
+                }
+            }
+        }
+        // Cat APIs answer in plain text unless asked for JSON
+        Err(_) => print!("{}", text),
+    }
+
+    if !status_code.is_success() {
+        bail!("Request failed with status {}", status_code);
+    }
+
+    Ok(())
+}
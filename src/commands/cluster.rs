@@ -0,0 +1,83 @@
+use anyhow::Result;
+use clap::{Parser, Subcommand, ValueEnum};
+use colored::{ColoredString, Colorize};
+use elasticsearch::params::WaitForStatus;
+
+use crate::application::Application;
+
+use super::cluster_health;
+use super::cluster_info;
+use super::cluster_settings;
+use super::cluster_stats;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    #[command(subcommand)]
+    sub_commands: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    /// Displays the cluster health
+    Health(cluster_health::Arguments),
+    /// Displays the cluster name, uuid and version
+    Info(cluster_info::Arguments),
+    /// Gets, updates or resets the cluster settings
+    Settings(cluster_settings::Arguments),
+    /// Displays a summary of the cluster statistics
+    Stats(cluster_stats::Arguments),
+}
+
+/// Cluster health, ordered from the healthiest
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum HealthStatus {
+    Green,
+    Yellow,
+    Red,
+}
+
+impl HealthStatus {
+    pub fn parse(status: &str) -> Option<HealthStatus> {
+        match status {
+            "green" => Some(HealthStatus::Green),
+            "yellow" => Some(HealthStatus::Yellow),
+            "red" => Some(HealthStatus::Red),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HealthStatus::Green => "green",
+            HealthStatus::Yellow => "yellow",
+            HealthStatus::Red => "red",
+        }
+    }
+
+    pub fn to_param(self) -> WaitForStatus {
+        match self {
+            HealthStatus::Green => WaitForStatus::Green,
+            HealthStatus::Yellow => WaitForStatus::Yellow,
+            HealthStatus::Red => WaitForStatus::Red,
+        }
+    }
+}
+
+/// Colors a health status (`green`, `yellow` or `red`) with its own color
+pub fn colorize_status(status: &str) -> ColoredString {
+    match HealthStatus::parse(status) {
+        Some(HealthStatus::Green) => status.green().bold(),
+        Some(HealthStatus::Yellow) => status.yellow().bold(),
+        Some(HealthStatus::Red) => status.red().bold(),
+        None => status.bold(),
+    }
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    match &args.sub_commands {
+        Commands::Health(args) => cluster_health::handle_command(args, application).await,
+        Commands::Info(args) => cluster_info::handle_command(args, application).await,
+        Commands::Settings(args) => cluster_settings::handle_command(args, application).await,
+        Commands::Stats(args) => cluster_stats::handle_command(args, application).await,
+    }
+}
@@ -0,0 +1,126 @@
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use elasticsearch::cluster::ClusterHealthParts;
+use serde::{Deserialize, Serialize};
+use tabled::{Table, Tabled};
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{output_json, output_yaml, print_table, Output};
+
+use super::cluster::{colorize_status, HealthStatus};
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Waits until the cluster reaches this status (or a better one)
+    #[arg(short, long, value_enum)]
+    wait_for_status: Option<HealthStatus>,
+    /// How long to wait for the status, e.g. 60s
+    #[arg(short, long, requires = "wait_for_status")]
+    timeout: Option<String>,
+    /// Exits with a non-zero code when the health is this status or worse
+    #[arg(short, long, value_enum)]
+    fail_on: Option<HealthStatus>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Tabled)]
+struct ClusterHealth {
+    #[tabled(rename = "cluster")]
+    cluster_name: String,
+    #[tabled(display_with = "display_status")]
+    status: String,
+    #[tabled(rename = "nodes")]
+    number_of_nodes: u64,
+    #[tabled(rename = "data nodes")]
+    number_of_data_nodes: u64,
+    #[tabled(rename = "primaries")]
+    active_primary_shards: u64,
+    #[tabled(rename = "shards")]
+    active_shards: u64,
+    #[tabled(rename = "relocating")]
+    relocating_shards: u64,
+    #[tabled(rename = "initializing")]
+    initializing_shards: u64,
+    #[tabled(rename = "unassigned")]
+    unassigned_shards: u64,
+    #[tabled(rename = "pending tasks")]
+    number_of_pending_tasks: u64,
+    #[tabled(rename = "active shards %", display_with = "display_percent")]
+    active_shards_percent_as_number: f64,
+    #[tabled(skip)]
+    timed_out: bool,
+}
+
+fn display_status(status: &str) -> String {
+    colorize_status(status).to_string()
+}
+
+fn display_percent(percent: &f64) -> String {
+    format!("{:.1}%", percent)
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+
+    let cluster = client.cluster();
+    let mut request = cluster.health(ClusterHealthParts::None);
+    if let Some(status) = args.wait_for_status {
+        request = request.wait_for_status(status.to_param());
+    }
+    if let Some(timeout) = &args.timeout {
+        request = request.timeout(timeout);
+    }
+
+    let response = request
+        .send()
+        .await
+        .context("Request error for getting cluster health")?;
+
+    // A wait for status that times out answers 408 with the current health
+    if !response.status_code().is_success() && response.status_code().as_u16() != 408 {
+        return handle_error_response(
+            &args.output,
+            response,
+            "Cannot get cluster health".to_string(),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let health: ClusterHealth = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for cluster health")?;
+
+    match args.output {
+        Output::Default => print_table(Table::new([&health]), "Cluster health"),
+        Output::Json => output_json(&health, args.pretty)?,
+        Output::Yaml => output_yaml(&health)?,
+    }
+
+    // Scripts waiting for a status must not go on when it is not reached
+    if let (Some(status), true) = (args.wait_for_status, health.timed_out) {
+        bail!(
+            "Timed out waiting for status {}, cluster health is {}",
+            status.as_str(),
+            health.status
+        );
+    }
+
+    if let Some(fail_on) = args.fail_on {
+        match HealthStatus::parse(&health.status) {
+            Some(status) if status >= fail_on => {
+                bail!("Cluster health is {}", health.status)
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use serde_json::Value;
+use tabled::builder::Builder;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{output_json, output_yaml, titled_rows, Output};
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = client
+        .info()
+        .send()
+        .await
+        .context("Request error for getting cluster info")?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            "Cannot get cluster info".to_string(),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let info: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for cluster info")?;
+
+    match args.output {
+        Output::Default => {
+            let version = &info["version"];
+            let rows = [
+                ("Cluster", &info["cluster_name"]),
+                ("UUID", &info["cluster_uuid"]),
+                ("Node", &info["name"]),
+                ("Version", &version["number"]),
+                ("Build flavor", &version["build_flavor"]),
+                ("Build type", &version["build_type"]),
+                ("Build date", &version["build_date"]),
+                ("Lucene version", &version["lucene_version"]),
+            ];
+
+            let mut builder = Builder::default();
+            for (name, value) in rows {
+                let value = match value {
+                    Value::String(value) => value.to_owned(),
+                    Value::Null => "-".to_string(),
+                    value => value.to_string(),
+                };
+                builder.push_record([name.bold().to_string(), value]);
+            }
+
+            let table = titled_rows(builder.build(), "Cluster info");
+
+            println!("{table}");
+        }
+        Output::Json => output_json(&info, args.pretty)?,
+        Output::Yaml => output_yaml(&info)?,
+    }
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+use crate::application::Application;
+
+use super::cluster_settings_get;
+use super::cluster_settings_reset;
+use super::cluster_settings_set;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    #[command(subcommand)]
+    sub_commands: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    /// Displays the cluster settings and where they are defined
+    Get(cluster_settings_get::Arguments),
+    /// Updates one or more cluster settings
+    Set(cluster_settings_set::Arguments),
+    /// Resets one or more cluster settings to their default value
+    Reset(cluster_settings_reset::Arguments),
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    match &args.sub_commands {
+        Commands::Get(args) => cluster_settings_get::handle_command(args, application).await,
+        Commands::Set(args) => cluster_settings_set::handle_command(args, application).await,
+        Commands::Reset(args) => cluster_settings_reset::handle_command(args, application).await,
+    }
+}
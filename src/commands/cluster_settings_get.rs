@@ -0,0 +1,182 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use serde_json::{Map, Value};
+use tabled::builder::Builder;
+use tabled::settings::object::Columns;
+use tabled::settings::{Modify, Width};
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{display, output_json, output_yaml, titled_table, Output};
+use crate::utils::pattern::matches_patterns;
+
+/// Scopes of the settings, from the highest precedence
+const SCOPES: [&str; 3] = ["transient", "persistent", "defaults"];
+
+/// Settings defined as transient or persistent override the defaults
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Also displays the settings left to their default value
+    #[arg(short, long, default_value_t = false)]
+    include_defaults: bool,
+    /// Keeps the setting names flat in JSON and YAML output (e.g. cluster.routing.allocation.enable)
+    #[arg(short, long, default_value_t = false)]
+    flat: bool,
+    /// Only displays the settings matching these names, comma separated (supports wildcards)
+    #[arg(short, long)]
+    key: Option<String>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let cluster = client.cluster();
+    let response = cluster
+        .get_settings()
+        .flat_settings(true)
+        .include_defaults(args.include_defaults)
+        .send()
+        .await
+        .context("Request error for getting cluster settings")?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            "Cannot get cluster settings".to_string(),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for cluster settings")?;
+
+    let mut settings = Map::new();
+    for scope in SCOPES {
+        if let Some(scope_settings) = response_body[scope].as_object() {
+            let scope_settings = scope_settings
+                .iter()
+                .filter(|(key, _)| match &args.key {
+                    Some(patterns) => matches_patterns(patterns, key),
+                    None => true,
+                })
+                .map(|(key, value)| (key.to_owned(), value.to_owned()))
+                .collect::<Map<String, Value>>();
+
+            let scope_settings = match args.flat {
+                true => Value::Object(scope_settings),
+                false => unflatten(&scope_settings),
+            };
+            settings.insert(scope.to_string(), scope_settings);
+        }
+    }
+
+    match args.output {
+        Output::Default => output_settings(&response_body, args),
+        Output::Json => output_json(&settings, args.pretty)?,
+        Output::Yaml => output_yaml(&settings)?,
+    }
+
+    Ok(())
+}
+
+fn output_settings(response_body: &Value, args: &Arguments) {
+    let mut rows: Vec<(&String, &Value, usize)> = SCOPES
+        .iter()
+        .enumerate()
+        .filter_map(|(precedence, scope)| {
+            let settings = response_body[scope].as_object()?;
+            Some(
+                settings
+                    .iter()
+                    .map(move |(key, value)| (key, value, precedence)),
+            )
+        })
+        .flatten()
+        .filter(|(key, _, _)| match &args.key {
+            Some(patterns) => matches_patterns(patterns, key),
+            None => true,
+        })
+        .collect();
+    rows.sort_by_key(|(key, _, precedence)| (*key, *precedence));
+
+    let mut builder = Builder::default();
+    builder.set_header(["setting", "value", "scope"]);
+
+    let mut overrides = 0;
+    let mut previous: Option<&String> = None;
+    for (key, value, precedence) in rows {
+        let overridden = previous == Some(key);
+        previous = Some(key);
+
+        let scope = SCOPES[precedence];
+        let (key, value, scope) = match (scope, overridden) {
+            ("defaults", _) => (key.to_string(), display(value), "default".dimmed()),
+            (_, true) => (
+                key.dimmed().to_string(),
+                display(value).dimmed().to_string(),
+                format!("{} (overridden)", scope).dimmed(),
+            ),
+            (_, false) => {
+                overrides += 1;
+                (
+                    key.bold().to_string(),
+                    display(value).bold().to_string(),
+                    match scope {
+                        "transient" => scope.yellow(),
+                        _ => scope.green(),
+                    },
+                )
+            }
+        };
+
+        builder.push_record([key, value, scope.to_string()]);
+    }
+
+    let mut table = titled_table(builder.build(), "Cluster settings");
+    table.with(Modify::new(Columns::single(1)).with(Width::wrap(80)));
+
+    println!("{table}");
+    println!("{} settings differ from the defaults", overrides);
+}
+
+/// Nests flat setting names on their dots, like Elasticsearch does without `flat_settings`
+fn unflatten(settings: &Map<String, Value>) -> Value {
+    let mut nested = Map::new();
+
+    for (key, value) in settings {
+        let mut object = &mut nested;
+        let mut parts = key.split('.').peekable();
+
+        while let Some(part) = parts.next() {
+            if parts.peek().is_none() {
+                object.insert(part.to_string(), value.to_owned());
+                break;
+            }
+
+            // A setting can't be both a value and a parent, the remaining name is then kept flat
+            if matches!(object.get(part), Some(value) if !value.is_object()) {
+                let rest: Vec<&str> = parts.collect();
+                object.insert(format!("{}.{}", part, rest.join(".")), value.to_owned());
+                break;
+            }
+
+            object = object
+                .entry(part)
+                .or_insert_with(|| Value::Object(Map::new()))
+                .as_object_mut()
+                .unwrap();
+        }
+    }
+
+    Value::Object(nested)
+}
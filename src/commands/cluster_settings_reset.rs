@@ -0,0 +1,50 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use serde_json::{json, Map, Value};
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_response;
+use crate::utils::output::Output;
+
+/// Settings are reset in both the persistent and the transient scopes
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the settings to reset (supports wildcards, e.g. cluster.routing.allocation.*)
+    #[arg(required = true)]
+    keys: Vec<String>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let settings: Map<String, Value> = args
+        .keys
+        .iter()
+        .map(|key| (key.to_owned(), Value::Null))
+        .collect();
+    let keys = args.keys.join(", ");
+
+    let client = application.get_http_client()?;
+    let cluster = client.cluster();
+    let response = cluster
+        .put_settings()
+        .flat_settings(true)
+        .body(json!({ "persistent": settings, "transient": settings }))
+        .send()
+        .await
+        .context(format!("Request error for resetting {}", keys))?;
+
+    handle_response(
+        &args.output,
+        response,
+        format!("Settings reset to default: {}", keys.bold()),
+        format!("Cannot reset {}", keys.bold()),
+        args.pretty,
+    )
+    .await
+}
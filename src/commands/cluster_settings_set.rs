@@ -0,0 +1,72 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use serde_json::{json, Map, Value};
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_response;
+use crate::utils::output::Output;
+
+/// Settings are persistent unless --transient is set, transient ones are lost on a full cluster restart
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Setting as key=value, can be repeated (e.g. cluster.routing.allocation.enable=primaries)
+    #[arg(required = true, value_parser = parse_setting)]
+    settings: Vec<(String, String)>,
+    /// Updates the transient settings instead of the persistent ones
+    #[arg(short, long, default_value_t = false)]
+    transient: bool,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+fn parse_setting(setting: &str) -> Result<(String, String), String> {
+    match setting.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("`{}` must be formatted as key=value", setting)),
+    }
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let scope = match args.transient {
+        true => "transient",
+        false => "persistent",
+    };
+    let settings: Map<String, Value> = args
+        .settings
+        .iter()
+        .map(|(key, value)| (key.to_owned(), Value::String(value.to_owned())))
+        .collect();
+    let names: Vec<&str> = args.settings.iter().map(|(key, _)| key.as_str()).collect();
+
+    let client = application.get_http_client()?;
+    let cluster = client.cluster();
+    let response = cluster
+        .put_settings()
+        .flat_settings(true)
+        .body(json!({ scope: settings }))
+        .send()
+        .await
+        .context("Request error for updating cluster settings")?;
+
+    handle_response(
+        &args.output,
+        response,
+        format!(
+            "Settings updated in {} scope: {}",
+            scope,
+            args.settings
+                .iter()
+                .map(|(key, value)| format!("{}={}", key.bold(), value))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        format!("Cannot update {}", names.join(", ").bold()),
+        args.pretty,
+    )
+    .await
+}
@@ -0,0 +1,154 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::cluster::ClusterStatsParts;
+use serde_json::Value;
+use tabled::builder::Builder;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{
+    format_bytes, format_percent, output_json, output_yaml, titled_rows, Output,
+};
+
+use super::cluster::colorize_status;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = client
+        .cluster()
+        .stats(ClusterStatsParts::None)
+        .send()
+        .await
+        .context("Request error for getting cluster stats")?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            "Cannot get cluster stats".to_string(),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let stats: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for cluster stats")?;
+
+    match args.output {
+        Output::Default => output_stats_summary(&stats),
+        Output::Json => output_json(&stats, args.pretty)?,
+        Output::Yaml => output_yaml(&stats)?,
+    }
+
+    Ok(())
+}
+
+fn output_stats_summary(stats: &Value) {
+    let nodes = &stats["nodes"];
+    let indices = &stats["indices"];
+    let count = &nodes["count"];
+    let shards = &indices["shards"];
+
+    let versions = nodes["versions"]
+        .as_array()
+        .map(|versions| {
+            versions
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<&str>>()
+                .join(", ")
+        })
+        .unwrap_or_default();
+
+    let heap_used = nodes["jvm"]["mem"]["heap_used_in_bytes"]
+        .as_u64()
+        .unwrap_or(0);
+    let heap_max = nodes["jvm"]["mem"]["heap_max_in_bytes"]
+        .as_u64()
+        .unwrap_or(0);
+    let disk_total = nodes["fs"]["total_in_bytes"].as_u64().unwrap_or(0);
+    let disk_available = nodes["fs"]["available_in_bytes"].as_u64().unwrap_or(0);
+    let disk_used = disk_total.saturating_sub(disk_available);
+
+    let rows = [
+        (
+            "Cluster",
+            stats["cluster_name"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        ),
+        (
+            "Status",
+            colorize_status(stats["status"].as_str().unwrap_or_default()).to_string(),
+        ),
+        (
+            "Nodes",
+            format!(
+                "{} (master: {}, data: {}, ingest: {})",
+                count["total"], count["master"], count["data"], count["ingest"]
+            ),
+        ),
+        ("Versions", versions),
+        ("Indices", indices["count"].to_string()),
+        (
+            "Shards",
+            format!(
+                "{} (primaries: {}, replication: {:.1})",
+                shards["total"],
+                shards["primaries"],
+                shards["replication"].as_f64().unwrap_or(0.0)
+            ),
+        ),
+        ("Documents", indices["docs"]["count"].to_string()),
+        (
+            "Store size",
+            format_bytes(indices["store"]["size_in_bytes"].as_u64().unwrap_or(0)),
+        ),
+        (
+            "Disk",
+            format!(
+                "{} / {} used ({})",
+                format_bytes(disk_used),
+                format_bytes(disk_total),
+                format_percent(disk_used, disk_total)
+            ),
+        ),
+        (
+            "JVM heap",
+            format!(
+                "{} / {} used ({})",
+                format_bytes(heap_used),
+                format_bytes(heap_max),
+                format_percent(heap_used, heap_max)
+            ),
+        ),
+        (
+            "OS memory",
+            format!("{}% used", nodes["os"]["mem"]["used_percent"]),
+        ),
+        ("CPU", format!("{}%", nodes["process"]["cpu"]["percent"])),
+    ];
+
+    let mut builder = Builder::default();
+    for (name, value) in rows {
+        builder.push_record([name.bold().to_string(), value]);
+    }
+
+    let table = titled_rows(builder.build(), "Cluster stats");
+
+    println!("{table}");
+}
@@ -0,0 +1,85 @@
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::{CountParts, Elasticsearch};
+use serde_json::{json, Value};
+
+use crate::application::Application;
+use crate::utils::output::{output_json, output_yaml, Output};
+
+use super::search::lucene_query;
+
+/// Counts the documents of indices, optionally matching a query
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Indices to count, comma separated (supports wildcards)
+    index: String,
+    /// Query in the Lucene query string syntax
+    #[arg(short, long)]
+    query: Option<String>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let query = args.query.as_deref().map(lucene_query);
+    let count = count_documents(&client, &[&args.index], &query).await?;
+
+    let result = json!({ "index": args.index, "query": args.query, "count": count });
+
+    match args.output {
+        Output::Default => println!(
+            "{} documents in {}",
+            count.to_string().bold(),
+            args.index.bold()
+        ),
+        Output::Json => output_json(&result, args.pretty)?,
+        Output::Yaml => output_yaml(&result)?,
+    }
+
+    Ok(())
+}
+
+/// Counts the documents of indices matching an optional query
+pub async fn count_documents(
+    client: &Elasticsearch,
+    indices: &[&str],
+    query: &Option<Value>,
+) -> Result<u64> {
+    let body = match query {
+        Some(query) => json!({ "query": query }),
+        None => json!({}),
+    };
+
+    let response = client
+        .count(CountParts::Index(indices))
+        .body(body)
+        .send()
+        .await
+        .context(format!(
+            "Request error for counting documents of {}",
+            indices.join(",")
+        ))?;
+
+    let status_code = response.status_code();
+    let response_body: Value = response.json().await?;
+
+    if !status_code.is_success() {
+        bail!(
+            "Cannot count documents of {}: {}",
+            indices.join(","),
+            response_body["error"]["reason"]
+                .as_str()
+                .unwrap_or(status_code.as_str())
+        );
+    }
+
+    response_body["count"]
+        .as_u64()
+        .context("Missing count in count response")
+}
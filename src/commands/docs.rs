@@ -0,0 +1,140 @@
+use anyhow::Result;
+use clap::{Parser, Subcommand, ValueEnum};
+use colored::Colorize;
+use elasticsearch::http::response::Response;
+use elasticsearch::http::StatusCode;
+use elasticsearch::params::Refresh;
+use serde_json::Value;
+
+use crate::application::Application;
+use crate::utils::output::{
+    output_error_table, output_json, output_yaml, print_error, print_success, Output,
+};
+
+use super::docs_delete;
+use super::docs_delete_by_query;
+use super::docs_exists;
+use super::docs_get;
+use super::docs_index;
+use super::docs_update;
+use super::docs_update_by_query;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    #[command(subcommand)]
+    sub_commands: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    /// Retrieves a document by id
+    Get(docs_get::Arguments),
+    /// Creates or replaces a document
+    Index(docs_index::Arguments),
+    /// Partially updates a document
+    Update(docs_update::Arguments),
+    /// Deletes a document
+    Delete(docs_delete::Arguments),
+    /// Checks whether a document exists
+    Exists(docs_exists::Arguments),
+    /// Updates all the documents matching a query
+    UpdateByQuery(docs_update_by_query::Arguments),
+    /// Deletes all the documents matching a query
+    DeleteByQuery(docs_delete_by_query::Arguments),
+}
+
+/// When changes made by a write request are made visible to search
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum RefreshPolicy {
+    /// Refreshes the affected shards immediately
+    True,
+    /// Does not refresh (default)
+    False,
+    /// Waits for the next refresh before answering
+    #[value(name = "wait_for")]
+    WaitFor,
+}
+
+impl RefreshPolicy {
+    pub fn to_param(self) -> Refresh {
+        match self {
+            RefreshPolicy::True => Refresh::True,
+            RefreshPolicy::False => Refresh::False,
+            RefreshPolicy::WaitFor => Refresh::WaitFor,
+        }
+    }
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    match &args.sub_commands {
+        Commands::Get(args) => docs_get::handle_command(args, application).await,
+        Commands::Index(args) => docs_index::handle_command(args, application).await,
+        Commands::Update(args) => docs_update::handle_command(args, application).await,
+        Commands::Delete(args) => docs_delete::handle_command(args, application).await,
+        Commands::Exists(args) => docs_exists::handle_command(args, application).await,
+        Commands::UpdateByQuery(args) => {
+            docs_update_by_query::handle_command(args, application).await
+        }
+        Commands::DeleteByQuery(args) => {
+            docs_delete_by_query::handle_command(args, application).await
+        }
+    }
+}
+
+/// Prints the outcome of an index, update or delete request
+pub async fn handle_write_response(
+    output: &Output,
+    response: Response,
+    error_msg: String,
+    pretty: bool,
+) -> Result<()> {
+    let status_code = response.status_code();
+    let response_body: Value = response.json().await?;
+
+    if !status_code.is_success() {
+        return output_document_error(output, status_code, &response_body, error_msg, pretty);
+    }
+
+    match output {
+        Output::Default => print_success(format!(
+            "Document {} {} in {} (version {}, seq_no {}, primary_term {})",
+            response_body["_id"].as_str().unwrap_or_default().bold(),
+            response_body["result"].as_str().unwrap_or_default(),
+            response_body["_index"].as_str().unwrap_or_default().bold(),
+            response_body["_version"],
+            response_body["_seq_no"],
+            response_body["_primary_term"],
+        )),
+        Output::Json => output_json(&response_body, pretty)?,
+        Output::Yaml => output_yaml(&response_body)?,
+    }
+
+    Ok(())
+}
+
+/// Document APIs answer a missing document without an error body (`"found": false`,
+/// `"result": "not_found"`), so the response cannot go through `handle_error_response`
+pub fn output_document_error(
+    output: &Output,
+    status_code: StatusCode,
+    response_body: &Value,
+    error_msg: String,
+    pretty: bool,
+) -> Result<()> {
+    match output {
+        Output::Default => {
+            let reason = match &response_body["error"] {
+                Value::Object(error) => error["reason"].as_str().unwrap_or_default(),
+                Value::String(error) => error,
+                _ => "document not found",
+            };
+
+            print_error(error_msg);
+            output_error_table(reason, status_code.as_str());
+        }
+        Output::Json => output_json(response_body, pretty)?,
+        Output::Yaml => output_yaml(response_body)?,
+    }
+
+    Ok(())
+}
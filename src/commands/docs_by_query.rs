@@ -0,0 +1,197 @@
+use anyhow::{bail, Context, Result};
+use clap::Args;
+use colored::Colorize;
+use elasticsearch::params::Slices;
+use elasticsearch::{DeleteByQueryParts, Elasticsearch, UpdateByQueryParts};
+use serde_json::Value;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{output_json, output_yaml, print_success, Output};
+
+use super::count::count_documents;
+use super::reindex::{
+    parse_requests_per_second, parse_slices, wait_for_task, ConflictsMode, PollArguments,
+};
+
+/// Update by query and delete by query only differ by their request builder and body
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operation {
+    Update,
+    Delete,
+}
+
+impl Operation {
+    fn label(self) -> &'static str {
+        match self {
+            Operation::Update => "Update by query",
+            Operation::Delete => "Delete by query",
+        }
+    }
+
+    fn past_tense(self) -> &'static str {
+        match self {
+            Operation::Update => "updated",
+            Operation::Delete => "deleted",
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct ByQueryArguments {
+    /// Maximum number of documents to process
+    #[arg(long)]
+    max_docs: Option<i64>,
+    /// Number of slices the task is divided into (`auto` or a number)
+    #[arg(long, value_parser = parse_slices)]
+    slices: Option<Slices>,
+    /// Throttles the task in sub-requests per second (`unlimited` or a number)
+    #[arg(long, value_parser = parse_requests_per_second)]
+    requests_per_second: Option<i64>,
+    /// Behaviour when version conflicts occur
+    #[arg(long, value_enum)]
+    conflicts: Option<ConflictsMode>,
+    /// Prints the task id and exits without waiting for completion
+    #[arg(short, long, default_value_t = false)]
+    detach: bool,
+    #[command(flatten)]
+    poll: PollArguments,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    pub output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+impl ByQueryArguments {
+    /// Number of matching documents the task will process
+    pub fn limit(&self, count: u64) -> u64 {
+        match self.max_docs {
+            Some(max_docs) => count.min(max_docs.max(0) as u64),
+            None => count,
+        }
+    }
+}
+
+/// Applies the shared parameters to an update by query or a delete by query request, their
+/// builders have the same methods but no common trait
+macro_rules! with_by_query_params {
+    ($request:expr, $args:expr) => {{
+        let mut request = $request.wait_for_completion(false);
+        if let Some(max_docs) = $args.max_docs {
+            request = request.max_docs(max_docs);
+        }
+        if let Some(slices) = &$args.slices {
+            request = request.slices(slices.clone());
+        }
+        if let Some(requests_per_second) = $args.requests_per_second {
+            request = request.requests_per_second(requests_per_second);
+        }
+        if let Some(conflicts) = $args.conflicts {
+            request = request.conflicts(conflicts.to_param());
+        }
+        request
+    }};
+}
+
+/// Counts the documents matching the query and reports it
+pub async fn count_matching_documents(
+    client: &Elasticsearch,
+    index: &str,
+    query: &Option<Value>,
+    output: &Output,
+) -> Result<u64> {
+    let count = count_documents(client, &[index], query).await?;
+    if let Output::Default = output {
+        println!(
+            "{} documents of {} match the query",
+            count.to_string().bold(),
+            index.bold()
+        );
+    }
+
+    Ok(count)
+}
+
+/// Starts the operation as a task, then follows it unless it is detached
+pub async fn run_by_query(
+    operation: Operation,
+    index: &str,
+    body: Value,
+    args: &ByQueryArguments,
+    client: &Elasticsearch,
+    application: &Application,
+) -> Result<()> {
+    let indices = [index];
+    let response = match operation {
+        Operation::Update => {
+            let request = client.update_by_query(UpdateByQueryParts::Index(&indices));
+            with_by_query_params!(request, args).body(body).send().await
+        }
+        Operation::Delete => {
+            let request = client.delete_by_query(DeleteByQueryParts::Index(&indices));
+            with_by_query_params!(request, args).body(body).send().await
+        }
+    }
+    .context(format!(
+        "Request error for {} on {}",
+        operation.label().to_lowercase(),
+        index
+    ))?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            format!(
+                "Documents of {} cannot be {}",
+                index.bold(),
+                operation.past_tense()
+            ),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let response_body: Value = response.json().await?;
+
+    let task_id = match response_body.get("task").and_then(Value::as_str) {
+        Some(task) => task.to_string(),
+        None => bail!(
+            "Missing task in {} response",
+            operation.label().to_lowercase()
+        ),
+    };
+
+    if args.detach {
+        match args.output {
+            Output::Default => {
+                print_success(format!(
+                    "{} started with task id {}",
+                    operation.label(),
+                    task_id.bold()
+                ));
+                println!(
+                    "Run `escli {} tasks wait {}` to follow its progress",
+                    application.cluster_name(),
+                    task_id
+                );
+            }
+            Output::Json => output_json(&response_body, args.pretty)?,
+            Output::Yaml => output_yaml(&response_body)?,
+        }
+
+        return Ok(());
+    }
+
+    wait_for_task(
+        &task_id,
+        client,
+        operation.label(),
+        args.poll.interval(),
+        &args.output,
+        args.pretty,
+    )
+    .await
+}
@@ -0,0 +1,67 @@
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use serde_json::json;
+
+use crate::application::Application;
+use crate::utils::input::{check_confirmable_input, confirm};
+
+use super::docs_by_query::{count_matching_documents, run_by_query, ByQueryArguments, Operation};
+use super::search::read_query;
+
+/// Runs as a task, Ctrl-C offers to cancel, leave or rethrottle it
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the index
+    index: String,
+    /// Query selecting the documents to delete, in the Lucene query string syntax
+    #[arg(short, long, conflicts_with = "dsl", required_unless_present = "dsl")]
+    query: Option<String>,
+    /// Query DSL selecting the documents to delete, inline JSON or path to a JSON file
+    #[arg(long)]
+    dsl: Option<String>,
+    /// Deletes the documents without asking for confirmation
+    #[arg(short, long, default_value_t = false)]
+    yes: bool,
+    #[command(flatten)]
+    by_query: ByQueryArguments,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    if let Some(dsl) = &args.dsl {
+        check_confirmable_input(dsl, "--dsl", args.yes)?;
+    }
+    let query = read_query(&args.query, &args.dsl)?.context("Missing --query or --dsl")?;
+
+    let client = application.get_http_client()?;
+
+    let count = count_matching_documents(
+        &client,
+        &args.index,
+        &Some(query.clone()),
+        &args.by_query.output,
+    )
+    .await?;
+    if count == 0 {
+        return Ok(());
+    }
+
+    if !args.yes
+        && !confirm(&format!(
+            "Delete {} documents from {}?",
+            args.by_query.limit(count),
+            args.index
+        ))?
+    {
+        bail!("Documents not deleted");
+    }
+
+    run_by_query(
+        Operation::Delete,
+        &args.index,
+        json!({ "query": query }),
+        &args.by_query,
+        &client,
+        application,
+    )
+    .await
+}
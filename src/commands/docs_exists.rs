@@ -0,0 +1,69 @@
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::http::StatusCode;
+use elasticsearch::ExistsParts;
+use serde_json::json;
+
+use crate::application::Application;
+use crate::utils::output::{output_json, output_yaml, print_success, Output};
+
+/// Exits with a non-zero code when the document does not exist
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the index
+    index: String,
+    /// Id of the document
+    id: String,
+    /// Routing value used to index the document
+    #[arg(short, long)]
+    routing: Option<String>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+
+    let mut request = client.exists(ExistsParts::IndexId(&args.index, &args.id));
+    if let Some(routing) = &args.routing {
+        request = request.routing(routing);
+    }
+
+    let response = request.send().await.context(format!(
+        "Request error for checking document {} in {}",
+        args.id, args.index
+    ))?;
+
+    let exists = match response.status_code() {
+        StatusCode::OK => true,
+        StatusCode::NOT_FOUND => false,
+        status_code => bail!(
+            "Cannot check document {} in {} (status code {})",
+            args.id,
+            args.index,
+            status_code
+        ),
+    };
+
+    match args.output {
+        Output::Default if exists => print_success(format!(
+            "Document {} exists in {}",
+            args.id.bold(),
+            args.index.bold()
+        )),
+        Output::Default => {}
+        Output::Json => output_json(&json!({ "exists": exists }), args.pretty)?,
+        Output::Yaml => output_yaml(&json!({ "exists": exists }))?,
+    }
+
+    if !exists {
+        bail!("Document {} does not exist in {}", args.id, args.index);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::GetParts;
+use serde_json::Value;
+
+use crate::application::Application;
+use crate::utils::output::{output_json, output_yaml, Output};
+
+use super::docs::output_document_error;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the index
+    index: String,
+    /// Id of the document
+    id: String,
+    /// Routing value used to index the document
+    #[arg(short, long)]
+    routing: Option<String>,
+    /// Source fields to return, comma separated (all fields by default)
+    #[arg(long, value_delimiter = ',')]
+    source_includes: Vec<String>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let source_includes: Vec<&str> = args.source_includes.iter().map(String::as_str).collect();
+
+    let mut request = client.get(GetParts::IndexId(&args.index, &args.id));
+    if let Some(routing) = &args.routing {
+        request = request.routing(routing);
+    }
+    if !source_includes.is_empty() {
+        request = request._source_includes(&source_includes);
+    }
+
+    let response = request.send().await.context(format!(
+        "Request error for getting document {} in {}",
+        args.id, args.index
+    ))?;
+
+    let status_code = response.status_code();
+    let response_body: Value = response.json().await?;
+
+    if !status_code.is_success() {
+        return output_document_error(
+            &args.output,
+            status_code,
+            &response_body,
+            format!(
+                "Cannot get document {} in {}",
+                args.id.bold(),
+                args.index.bold()
+            ),
+            args.pretty,
+        );
+    }
+
+    match args.output {
+        Output::Default => {
+            println!(
+                "{} {} (version {}, seq_no {}, primary_term {})",
+                response_body["_index"].as_str().unwrap_or_default().bold(),
+                response_body["_id"].as_str().unwrap_or_default().bold(),
+                response_body["_version"],
+                response_body["_seq_no"],
+                response_body["_primary_term"],
+            );
+            output_json(&response_body["_source"], true)?;
+        }
+        Output::Json => output_json(&response_body, args.pretty)?,
+        Output::Yaml => output_yaml(&response_body)?,
+    }
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::params::OpType;
+use elasticsearch::IndexParts;
+
+use crate::application::Application;
+use crate::utils::input::read_json_input;
+use crate::utils::output::Output;
+
+use super::docs::{handle_write_response, RefreshPolicy};
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the index
+    index: String,
+    /// Id of the document (generated by Elasticsearch when missing)
+    #[arg(long)]
+    id: Option<String>,
+    /// Path to the document in JSON format ("-" reads from stdin)
+    #[arg(short, long)]
+    file: String,
+    /// Fails if a document with the same id already exists
+    #[arg(long, default_value_t = false)]
+    create: bool,
+    /// Routing value used to route the document to a shard
+    #[arg(short, long)]
+    routing: Option<String>,
+    /// When the change is made visible to search
+    #[arg(long, value_enum)]
+    refresh: Option<RefreshPolicy>,
+    /// Only indexes if the document has this sequence number
+    #[arg(long, requires = "if_primary_term")]
+    if_seq_no: Option<i64>,
+    /// Only indexes if the document has this primary term
+    #[arg(long, requires = "if_seq_no")]
+    if_primary_term: Option<i64>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let document = read_json_input(&args.file, "document")?;
+    let client = application.get_http_client()?;
+
+    let parts = match &args.id {
+        Some(id) => IndexParts::IndexId(&args.index, id),
+        None => IndexParts::Index(&args.index),
+    };
+
+    let mut request = client.index(parts);
+    if args.create {
+        request = request.op_type(OpType::Create);
+    }
+    if let Some(routing) = &args.routing {
+        request = request.routing(routing);
+    }
+    if let Some(refresh) = args.refresh {
+        request = request.refresh(refresh.to_param());
+    }
+    if let Some(if_seq_no) = args.if_seq_no {
+        request = request.if_seq_no(if_seq_no);
+    }
+    if let Some(if_primary_term) = args.if_primary_term {
+        request = request.if_primary_term(if_primary_term);
+    }
+
+    let response = request.body(document).send().await.context(format!(
+        "Request error for indexing document in {}",
+        args.index
+    ))?;
+
+    handle_write_response(
+        &args.output,
+        response,
+        format!("Document cannot be indexed in {}", args.index.bold()),
+        args.pretty,
+    )
+    .await
+}
@@ -0,0 +1,112 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::UpdateParts;
+use serde_json::json;
+
+use crate::application::Application;
+use crate::utils::input::{read_json_input, read_text_input};
+use crate::utils::output::Output;
+
+use super::docs::{handle_write_response, RefreshPolicy};
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the index
+    index: String,
+    /// Id of the document
+    id: String,
+    /// Partial document to merge, inline JSON or path to a JSON file ("-" reads from stdin)
+    #[arg(
+        short,
+        long,
+        required_unless_present = "script",
+        conflicts_with = "script"
+    )]
+    doc: Option<String>,
+    /// Path to a painless script file updating the document
+    #[arg(short, long)]
+    script: Option<String>,
+    /// Parameters of the script, inline JSON or path to a JSON file
+    #[arg(long, requires = "script")]
+    script_params: Option<String>,
+    /// Indexes the partial document when the document does not exist
+    #[arg(long, default_value_t = false, requires = "doc")]
+    upsert: bool,
+    /// Number of retries when a version conflict occurs
+    #[arg(long)]
+    retry_on_conflict: Option<i64>,
+    /// Routing value used to route the document to a shard
+    #[arg(short, long)]
+    routing: Option<String>,
+    /// When the change is made visible to search
+    #[arg(long, value_enum)]
+    refresh: Option<RefreshPolicy>,
+    /// Only updates if the document has this sequence number
+    #[arg(long, requires = "if_primary_term")]
+    if_seq_no: Option<i64>,
+    /// Only updates if the document has this primary term
+    #[arg(long, requires = "if_seq_no")]
+    if_primary_term: Option<i64>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let mut body = json!({});
+    if let Some(doc) = &args.doc {
+        body["doc"] = read_json_input(doc, "partial document")?;
+        if args.upsert {
+            body["doc_as_upsert"] = json!(true);
+        }
+    }
+    if let Some(script_path) = &args.script {
+        body["script"] = json!({
+            "source": read_text_input(script_path, "script")?,
+            "lang": "painless"
+        });
+        if let Some(params) = &args.script_params {
+            body["script"]["params"] = read_json_input(params, "script parameters")?;
+        }
+    }
+
+    let client = application.get_http_client()?;
+
+    let mut request = client.update(UpdateParts::IndexId(&args.index, &args.id));
+    if let Some(retry_on_conflict) = args.retry_on_conflict {
+        request = request.retry_on_conflict(retry_on_conflict);
+    }
+    if let Some(routing) = &args.routing {
+        request = request.routing(routing);
+    }
+    if let Some(refresh) = args.refresh {
+        request = request.refresh(refresh.to_param());
+    }
+    if let Some(if_seq_no) = args.if_seq_no {
+        request = request.if_seq_no(if_seq_no);
+    }
+    if let Some(if_primary_term) = args.if_primary_term {
+        request = request.if_primary_term(if_primary_term);
+    }
+
+    let response = request.body(body).send().await.context(format!(
+        "Request error for updating document {} in {}",
+        args.id, args.index
+    ))?;
+
+    handle_write_response(
+        &args.output,
+        response,
+        format!(
+            "Document {} cannot be updated in {}",
+            args.id.bold(),
+            args.index.bold()
+        ),
+        args.pretty,
+    )
+    .await
+}
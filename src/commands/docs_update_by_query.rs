@@ -0,0 +1,66 @@
+use anyhow::Result;
+use clap::Parser;
+use serde_json::json;
+
+use crate::application::Application;
+use crate::utils::input::{read_json_input, read_text_input};
+
+use super::docs_by_query::{count_matching_documents, run_by_query, ByQueryArguments, Operation};
+use super::search::read_query;
+
+/// Runs as a task, Ctrl-C offers to cancel, leave or rethrottle it
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the index
+    index: String,
+    /// Query selecting the documents to update, in the Lucene query string syntax (all documents by default)
+    #[arg(short, long, conflicts_with = "dsl")]
+    query: Option<String>,
+    /// Query DSL selecting the documents to update, inline JSON or path to a JSON file
+    #[arg(long)]
+    dsl: Option<String>,
+    /// Path to a painless script file updating each document ("-" reads from stdin)
+    #[arg(short, long)]
+    script: Option<String>,
+    /// Parameters of the script, inline JSON or path to a JSON file
+    #[arg(long, requires = "script")]
+    script_params: Option<String>,
+    #[command(flatten)]
+    by_query: ByQueryArguments,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let query = read_query(&args.query, &args.dsl)?;
+
+    let mut body = json!({});
+    if let Some(query) = &query {
+        body["query"] = query.clone();
+    }
+    if let Some(script_path) = &args.script {
+        body["script"] = json!({
+            "source": read_text_input(script_path, "script")?,
+            "lang": "painless"
+        });
+        if let Some(params) = &args.script_params {
+            body["script"]["params"] = read_json_input(params, "script params")?;
+        }
+    }
+
+    let client = application.get_http_client()?;
+
+    let count =
+        count_matching_documents(&client, &args.index, &query, &args.by_query.output).await?;
+    if count == 0 {
+        return Ok(());
+    }
+
+    run_by_query(
+        Operation::Update,
+        &args.index,
+        body,
+        &args.by_query,
+        &client,
+        application,
+    )
+    .await
+}
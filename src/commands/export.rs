@@ -0,0 +1,520 @@
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::sync::Mutex;
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::http::response::Response;
+use elasticsearch::http::StatusCode;
+use elasticsearch::{
+    ClearScrollParts, Elasticsearch, OpenPointInTimeParts, ScrollParts, SearchParts,
+};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use futures::future::try_join_all;
+use indicatif::{ProgressBar, ProgressStyle};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::application::Application;
+use crate::utils::interrupt::Interrupts;
+use crate::utils::output::{output_json, output_yaml, print_success, Output};
+
+use super::count::count_documents;
+use super::search::read_query;
+
+/// Dumps all the documents of an index as NDJSON
+///
+/// Documents are paged with a point in time and search_after, or with a scroll on clusters
+/// older than 7.12 which cannot sort on _shard_doc. Search contexts are released even when the export
+/// fails or is interrupted.
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Index (or alias) to export
+    index: String,
+    /// Query selecting the documents to export, in the Lucene query string syntax
+    #[arg(short, long, conflicts_with = "dsl")]
+    query: Option<String>,
+    /// Query DSL selecting the documents to export, inline JSON or path to a JSON file
+    #[arg(long)]
+    dsl: Option<String>,
+    /// Path to the NDJSON file to write ("-" writes to stdout)
+    #[arg(long)]
+    out: String,
+    /// Compresses the file with gzip (implied by a .gz extension)
+    #[arg(short, long, default_value_t = false)]
+    gzip: bool,
+    /// Number of slices exported in parallel
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    slices: u32,
+    /// Number of documents fetched by request
+    #[arg(long, default_value_t = 1000)]
+    batch_size: i64,
+    /// Source fields to export, comma separated (all fields by default)
+    #[arg(long, value_delimiter = ',')]
+    source_includes: Vec<String>,
+    /// Writes only the document sources instead of _index, _id and _source
+    #[arg(long, default_value_t = false)]
+    source_only: bool,
+    /// Uses a scroll even when point in times are supported
+    #[arg(long, default_value_t = false)]
+    scroll: bool,
+    /// How long search contexts are kept alive between two requests
+    #[arg(long, default_value = "1m")]
+    keep_alive: String,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Method {
+    PointInTime,
+    Scroll,
+}
+
+#[derive(Debug, Serialize)]
+struct ExportSummary {
+    index: String,
+    out: String,
+    method: Method,
+    slices: u32,
+    docs: u64,
+}
+
+/// NDJSON destination, optionally compressed
+enum ExportWriter {
+    Plain(BufWriter<Box<dyn Write + Send>>),
+    Gzip(GzEncoder<BufWriter<Box<dyn Write + Send>>>),
+}
+
+impl ExportWriter {
+    fn create(path: &str, gzip: bool) -> Result<Self> {
+        let inner: Box<dyn Write + Send> = match path {
+            "-" => Box::new(io::stdout()),
+            _ => Box::new(File::create(path).context(format!("Cannot create file at {}", path))?),
+        };
+        let inner = BufWriter::new(inner);
+
+        Ok(match gzip {
+            true => ExportWriter::Gzip(GzEncoder::new(inner, Compression::default())),
+            false => ExportWriter::Plain(inner),
+        })
+    }
+
+    fn write_line(&mut self, document: &Value) -> Result<()> {
+        let writer: &mut dyn Write = match self {
+            ExportWriter::Plain(writer) => writer,
+            ExportWriter::Gzip(writer) => writer,
+        };
+
+        serde_json::to_writer(&mut *writer, document)?;
+        writer.write_all(b"\n")?;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<()> {
+        match self {
+            ExportWriter::Plain(mut writer) => writer.flush()?,
+            ExportWriter::Gzip(writer) => writer.finish()?.flush()?,
+        }
+        Ok(())
+    }
+}
+
+/// Search contexts opened by the export, released once it stops
+#[derive(Debug, Default)]
+struct SearchContexts {
+    pit_ids: BTreeSet<String>,
+    scroll_ids: BTreeSet<String>,
+}
+
+impl SearchContexts {
+    fn replace_scroll_id(&mut self, previous: Option<&str>, scroll_id: &str) {
+        if let Some(previous) = previous {
+            self.scroll_ids.remove(previous);
+        }
+        self.scroll_ids.insert(scroll_id.to_string());
+    }
+}
+
+/// State shared by the slices exported in parallel
+struct Export<'a> {
+    client: &'a Elasticsearch,
+    args: &'a Arguments,
+    query: Option<Value>,
+    writer: Mutex<ExportWriter>,
+    contexts: Mutex<SearchContexts>,
+    pb: ProgressBar,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let query = read_query(&args.query, &args.dsl)?;
+
+    let total = count_documents(&client, &[&args.index], &query).await?;
+    let gzip = args.gzip || args.out.ends_with(".gz");
+
+    let pb = ProgressBar::new(total);
+    pb.set_style(
+        ProgressStyle::with_template(
+            "{spinner:.blue} {prefix} [{elapsed_precise}] [{bar:40.cyan/blue}] {human_pos}/{human_len} docs ({per_sec}, {eta})",
+        )
+        .unwrap()
+        .progress_chars("=> "),
+    );
+    pb.set_prefix(format!("Export of {}", args.index));
+
+    let export = Export {
+        client: &client,
+        args,
+        query,
+        writer: Mutex::new(ExportWriter::create(&args.out, gzip)?),
+        contexts: Mutex::new(SearchContexts::default()),
+        pb: pb.clone(),
+    };
+
+    let mut interrupts = Interrupts::listen();
+    let result = tokio::select! {
+        result = run_export(&export) => result,
+        _ = interrupts.recv() => Err(anyhow!("Export interrupted")),
+    };
+
+    // Whatever happened, search contexts must not stay open on the cluster
+    let released = release_contexts(&client, &export.contexts).await;
+    pb.finish_and_clear();
+
+    let Export { writer, .. } = export;
+    let finished = writer.into_inner().unwrap().finish();
+
+    let method = result.context(format!("Export of {} failed", args.index))?;
+    released?;
+    finished.context(format!("Cannot write documents to {}", args.out))?;
+
+    let summary = ExportSummary {
+        index: args.index.to_owned(),
+        out: args.out.to_owned(),
+        method,
+        slices: args.slices,
+        docs: pb.position(),
+    };
+
+    match args.output {
+        // Documents are written to stdout
+        _ if args.out == "-" => {}
+        Output::Default => print_success(format!(
+            "{} documents exported from {} to {}",
+            summary.docs,
+            args.index.bold(),
+            args.out.bold()
+        )),
+        Output::Json => output_json(&summary, args.pretty)?,
+        Output::Yaml => output_yaml(&summary)?,
+    }
+
+    Ok(())
+}
+
+async fn run_export(export: &Export<'_>) -> Result<Method> {
+    let pit_id = match export.args.scroll || !sorts_on_shard_doc(export.client).await {
+        true => None,
+        false => open_point_in_time(export).await?,
+    };
+
+    match pit_id {
+        Some(pit_id) => {
+            try_join_all(
+                (0..export.args.slices).map(|slice| export_pit_slice(export, &pit_id, slice)),
+            )
+            .await?;
+            Ok(Method::PointInTime)
+        }
+        None => {
+            try_join_all((0..export.args.slices).map(|slice| export_scroll_slice(export, slice)))
+                .await?;
+            Ok(Method::Scroll)
+        }
+    }
+}
+
+/// Paging a point in time needs the _shard_doc tiebreaker, added in 7.12 while point in times
+/// exist since 7.10. The version is assumed recent enough when it cannot be read.
+async fn sorts_on_shard_doc(client: &Elasticsearch) -> bool {
+    let info: Option<Value> = match client.info().send().await {
+        Ok(response) if response.status_code().is_success() => response.json().await.ok(),
+        _ => None,
+    };
+
+    let version = info
+        .as_ref()
+        .and_then(|info| info["version"]["number"].as_str())
+        .map(|number| {
+            number
+                .split(['.', '-'])
+                .map(|part| part.parse::<u32>().unwrap_or(0))
+                .take(2)
+                .collect::<Vec<_>>()
+        });
+
+    match version.as_deref() {
+        Some([major, minor]) => (*major, *minor) >= (7, 12),
+        _ => true,
+    }
+}
+
+/// Returns `None` when the cluster does not support point in times
+async fn open_point_in_time(export: &Export<'_>) -> Result<Option<String>> {
+    let response = export
+        .client
+        .open_point_in_time(OpenPointInTimeParts::Index(&[&export.args.index]))
+        .keep_alive(&export.args.keep_alive)
+        .send()
+        .await
+        .context(format!(
+            "Request error for opening a point in time on {}",
+            export.args.index
+        ))?;
+
+    let status_code = response.status_code();
+    if !status_code.is_success() {
+        let text = response.text().await.unwrap_or_default();
+
+        // Clusters older than 7.10 have no point in time endpoint, scroll is used instead
+        let unsupported = status_code == StatusCode::METHOD_NOT_ALLOWED
+            || (status_code == StatusCode::BAD_REQUEST && text.contains("no handler found"));
+        if unsupported {
+            return Ok(None);
+        }
+
+        let reason = serde_json::from_str::<Value>(&text)
+            .ok()
+            .and_then(|error| error["error"]["reason"].as_str().map(str::to_string))
+            .unwrap_or(text);
+        bail!(
+            "Cannot open a point in time on {} (status code {}): {}",
+            export.args.index,
+            status_code,
+            reason
+        );
+    }
+
+    let response_body: Value = response.json().await?;
+    let pit_id = response_body["id"]
+        .as_str()
+        .context("Missing id in point in time response")?
+        .to_string();
+
+    export
+        .contexts
+        .lock()
+        .unwrap()
+        .pit_ids
+        .insert(pit_id.to_owned());
+
+    Ok(Some(pit_id))
+}
+
+async fn export_pit_slice(export: &Export<'_>, pit_id: &str, slice: u32) -> Result<()> {
+    let mut body = search_body(export, slice);
+    body["pit"] = json!({ "id": pit_id, "keep_alive": export.args.keep_alive });
+    body["sort"] = json!([{ "_shard_doc": "asc" }]);
+    body["track_total_hits"] = json!(false);
+
+    loop {
+        let response = export
+            .client
+            .search(SearchParts::None)
+            .body(body.clone())
+            .send()
+            .await
+            .context(format!("Request error for exporting slice {}", slice))?;
+
+        let response_body = read_page(response, slice).await?;
+
+        // The point in time id may change between two requests
+        if let Some(new_pit_id) = response_body["pit_id"].as_str() {
+            if body["pit"]["id"] != new_pit_id {
+                let mut contexts = export.contexts.lock().unwrap();
+                contexts.pit_ids.insert(new_pit_id.to_string());
+                body["pit"]["id"] = json!(new_pit_id);
+            }
+        }
+
+        let hits = write_hits(export, &response_body)?;
+        match hits.last() {
+            Some(last_hit) if hits.len() as i64 == export.args.batch_size => {
+                body["search_after"] = last_hit["sort"].to_owned();
+            }
+            _ => return Ok(()),
+        }
+    }
+}
+
+async fn export_scroll_slice(export: &Export<'_>, slice: u32) -> Result<()> {
+    let mut body = search_body(export, slice);
+    body["sort"] = json!(["_doc"]);
+
+    let response = export
+        .client
+        .search(SearchParts::Index(&[&export.args.index]))
+        .scroll(&export.args.keep_alive)
+        .body(body)
+        .send()
+        .await
+        .context(format!("Request error for exporting slice {}", slice))?;
+
+    let mut response_body = read_page(response, slice).await?;
+    let mut scroll_id: Option<String> = None;
+
+    loop {
+        let new_scroll_id = response_body["_scroll_id"]
+            .as_str()
+            .context("Missing _scroll_id in scroll response")?
+            .to_string();
+        export
+            .contexts
+            .lock()
+            .unwrap()
+            .replace_scroll_id(scroll_id.as_deref(), &new_scroll_id);
+        scroll_id = Some(new_scroll_id);
+
+        if write_hits(export, &response_body)?.is_empty() {
+            return Ok(());
+        }
+
+        let response = export
+            .client
+            .scroll(ScrollParts::None)
+            .body(json!({
+                "scroll": export.args.keep_alive,
+                "scroll_id": scroll_id
+            }))
+            .send()
+            .await
+            .context(format!("Request error for exporting slice {}", slice))?;
+
+        response_body = read_page(response, slice).await?;
+    }
+}
+
+fn search_body(export: &Export<'_>, slice: u32) -> Value {
+    let mut body = json!({ "size": export.args.batch_size });
+
+    if let Some(query) = &export.query {
+        body["query"] = query.to_owned();
+    }
+    if !export.args.source_includes.is_empty() {
+        body["_source"] = json!(export.args.source_includes);
+    }
+    if export.args.slices > 1 {
+        body["slice"] = json!({ "id": slice, "max": export.args.slices });
+    }
+
+    body
+}
+
+async fn read_page(response: Response, slice: u32) -> Result<Value> {
+    let status_code = response.status_code();
+    let response_body: Value = response
+        .json()
+        .await
+        .context(format!("Cannot parse JSON response of slice {}", slice))?;
+
+    if !status_code.is_success() {
+        bail!(
+            "Cannot export slice {}: {}",
+            slice,
+            response_body["error"]["reason"]
+                .as_str()
+                .unwrap_or(status_code.as_str())
+        );
+    }
+
+    Ok(response_body)
+}
+
+/// Writes the hits of a page and returns them
+fn write_hits<'v>(export: &Export<'_>, response_body: &'v Value) -> Result<&'v [Value]> {
+    let hits = match response_body["hits"]["hits"].as_array() {
+        Some(hits) => hits.as_slice(),
+        None => &[],
+    };
+
+    let mut writer = export.writer.lock().unwrap();
+    for hit in hits {
+        let document = match export.args.source_only {
+            true => hit["_source"].to_owned(),
+            false => {
+                let mut document = json!({
+                    "_index": hit["_index"],
+                    "_id": hit["_id"],
+                    "_source": hit["_source"]
+                });
+                if let Some(routing) = hit.get("_routing") {
+                    document["_routing"] = routing.to_owned();
+                }
+                document
+            }
+        };
+
+        writer
+            .write_line(&document)
+            .context(format!("Cannot write documents to {}", export.args.out))?;
+    }
+
+    export.pb.inc(hits.len() as u64);
+    Ok(hits)
+}
+
+async fn release_contexts(client: &Elasticsearch, contexts: &Mutex<SearchContexts>) -> Result<()> {
+    let SearchContexts {
+        pit_ids,
+        scroll_ids,
+    } = std::mem::take(&mut *contexts.lock().unwrap());
+
+    let mut failures = vec![];
+
+    for pit_id in pit_ids {
+        let response = client
+            .close_point_in_time()
+            .body(json!({ "id": pit_id }))
+            .send()
+            .await;
+
+        // A point in time replaced by a newer id is already gone
+        match response {
+            Ok(response) if response.status_code().is_success() => {}
+            Ok(response) if response.status_code().as_u16() == 404 => {}
+            _ => failures.push("point in time"),
+        }
+    }
+
+    if !scroll_ids.is_empty() {
+        let response = client
+            .clear_scroll(ClearScrollParts::None)
+            .body(json!({ "scroll_id": scroll_ids }))
+            .send()
+            .await;
+
+        match response {
+            Ok(response) if response.status_code().is_success() => {}
+            Ok(response) if response.status_code().as_u16() == 404 => {}
+            _ => failures.push("scroll"),
+        }
+    }
+
+    if !failures.is_empty() {
+        bail!(
+            "Cannot release {} search contexts, they will expire after their keep alive",
+            failures.join(" and ")
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::SearchParts;
+use serde_json::{json, Value};
+use tabled::builder::Builder;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{format_bar, output_json, output_yaml, print_table, Output};
+
+use super::search::lucene_query;
+
+/// Width of the bars, in characters
+const BAR_WIDTH: usize = 40;
+
+/// Intervals following the calendar (months and years have varying lengths), others are fixed
+const CALENDAR_INTERVALS: [&str; 7] = ["1m", "1h", "1d", "1w", "1M", "1q", "1y"];
+
+/// Displays the number of documents over time
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Indices to aggregate, comma separated (supports wildcards)
+    index: String,
+    /// Date field to aggregate
+    date_field: String,
+    /// Interval of the buckets (e.g. 30m, 1d, 1M)
+    #[arg(short, long, default_value = "1d")]
+    interval: String,
+    /// Query in the Lucene query string syntax
+    #[arg(short, long)]
+    query: Option<String>,
+    /// Time zone of the buckets (e.g. Europe/Paris or +01:00)
+    #[arg(short, long)]
+    time_zone: Option<String>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let interval_type = match CALENDAR_INTERVALS.contains(&args.interval.as_str()) {
+        true => "calendar_interval",
+        false => "fixed_interval",
+    };
+
+    let mut histogram = json!({
+        "field": args.date_field,
+        interval_type: args.interval
+    });
+    if let Some(time_zone) = &args.time_zone {
+        histogram["time_zone"] = json!(time_zone);
+    }
+
+    let mut body = json!({
+        "size": 0,
+        "aggs": {
+            "histogram": {
+                "date_histogram": histogram
+            }
+        }
+    });
+    if let Some(query) = &args.query {
+        body["query"] = lucene_query(query);
+    }
+
+    let client = application.get_http_client()?;
+    let indices: Vec<&str> = args.index.split(',').collect();
+    let response = client
+        .search(SearchParts::Index(&indices))
+        .body(body)
+        .send()
+        .await
+        .context(format!(
+            "Request error for aggregating {} of {}",
+            args.date_field, args.index
+        ))?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            format!(
+                "Cannot aggregate {} of {}",
+                args.date_field.bold(),
+                args.index.bold()
+            ),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for date histogram")?;
+    let aggregation = &response_body["aggregations"]["histogram"];
+
+    match args.output {
+        Output::Default => output_buckets(args, aggregation),
+        Output::Json => output_json(aggregation, args.pretty)?,
+        Output::Yaml => output_yaml(aggregation)?,
+    }
+
+    Ok(())
+}
+
+fn output_buckets(args: &Arguments, aggregation: &Value) {
+    let buckets = aggregation["buckets"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    let max = buckets
+        .iter()
+        .filter_map(|bucket| bucket["doc_count"].as_u64())
+        .max()
+        .unwrap_or(0);
+
+    let mut builder = Builder::default();
+    builder.set_header(["date", "count", ""]);
+
+    for bucket in &buckets {
+        let count = bucket["doc_count"].as_u64().unwrap_or(0);
+        let date = match bucket["key_as_string"].as_str() {
+            Some(date) => date.to_string(),
+            None => bucket["key"].to_string(),
+        };
+
+        builder.push_record([
+            date,
+            count.to_string(),
+            format_bar(count as f64, max as f64, BAR_WIDTH)
+                .blue()
+                .to_string(),
+        ]);
+    }
+
+    print_table(
+        builder.build(),
+        &format!("{} per {}", args.date_field, args.interval),
+    );
+}
@@ -0,0 +1,82 @@
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand};
+use elasticsearch::ilm::IlmExplainLifecycleParts;
+use elasticsearch::Elasticsearch;
+use serde_json::{Map, Value};
+
+use crate::application::Application;
+
+use super::ilm_explain;
+use super::ilm_move_to_step;
+use super::ilm_policies;
+use super::ilm_retry;
+use super::ilm_start;
+use super::ilm_status;
+use super::ilm_stop;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    #[command(subcommand)]
+    sub_commands: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    /// Manages the lifecycle policies
+    Policies(ilm_policies::Arguments),
+    /// Displays the lifecycle state of indices
+    Explain(ilm_explain::Arguments),
+    /// Retries the failed lifecycle step of an index
+    Retry(ilm_retry::Arguments),
+    /// Moves an index to another lifecycle step
+    MoveToStep(ilm_move_to_step::Arguments),
+    /// Displays whether index lifecycle management is running
+    Status(ilm_status::Arguments),
+    /// Starts index lifecycle management
+    Start(ilm_start::Arguments),
+    /// Stops index lifecycle management
+    Stop(ilm_stop::Arguments),
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    match &args.sub_commands {
+        Commands::Policies(args) => ilm_policies::handle_command(args, application).await,
+        Commands::Explain(args) => ilm_explain::handle_command(args, application).await,
+        Commands::Retry(args) => ilm_retry::handle_command(args, application).await,
+        Commands::MoveToStep(args) => ilm_move_to_step::handle_command(args, application).await,
+        Commands::Status(args) => ilm_status::handle_command(args, application).await,
+        Commands::Start(args) => ilm_start::handle_command(args, application).await,
+        Commands::Stop(args) => ilm_stop::handle_command(args, application).await,
+    }
+}
+
+/// Gets the lifecycle state of the indices matching a pattern, keyed by index name
+pub async fn explain_lifecycle(client: &Elasticsearch, index: &str) -> Result<Map<String, Value>> {
+    let response = client
+        .ilm()
+        .explain_lifecycle(IlmExplainLifecycleParts::Index(index))
+        .send()
+        .await
+        .context(format!(
+            "Request error for explaining lifecycle of {}",
+            index
+        ))?;
+
+    if !response.status_code().is_success() {
+        bail!(
+            "Cannot explain lifecycle of {} (status code {})",
+            index,
+            response.status_code()
+        );
+    }
+
+    let mut response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for lifecycle explain")?;
+
+    match response_body["indices"].take() {
+        Value::Object(indices) => Ok(indices),
+        _ => Ok(Map::new()),
+    }
+}
@@ -0,0 +1,126 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::ilm::IlmExplainLifecycleParts;
+use serde_json::{Map, Value};
+use tabled::builder::Builder;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{display, output_json, output_yaml, print_error, print_table, Output};
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Indices to explain, comma separated (supports wildcards)
+    index: String,
+    /// Only displays the indices whose lifecycle failed
+    #[arg(long, default_value_t = false)]
+    only_errors: bool,
+    /// Only displays the indices managed by a lifecycle policy
+    #[arg(long, default_value_t = false)]
+    only_managed: bool,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = client
+        .ilm()
+        .explain_lifecycle(IlmExplainLifecycleParts::Index(&args.index))
+        .only_errors(args.only_errors)
+        .only_managed(args.only_managed)
+        .send()
+        .await
+        .context(format!(
+            "Request error for explaining lifecycle of {}",
+            args.index
+        ))?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            format!("Cannot explain lifecycle of {}", args.index.bold()),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for lifecycle explain")?;
+    let indices = response_body["indices"]
+        .as_object()
+        .cloned()
+        .unwrap_or_default();
+
+    match args.output {
+        Output::Default => output_explanations(&indices),
+        Output::Json => output_json(&indices, args.pretty)?,
+        Output::Yaml => output_yaml(&indices)?,
+    }
+
+    Ok(())
+}
+
+fn output_explanations(indices: &Map<String, Value>) {
+    let mut builder = Builder::default();
+    builder.set_header([
+        "index",
+        "policy",
+        "phase",
+        "action",
+        "step",
+        "age",
+        "failed step",
+    ]);
+
+    for (name, index) in indices {
+        if index["managed"] == false {
+            builder.push_record([
+                name.to_owned(),
+                "unmanaged".dimmed().to_string(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+            ]);
+            continue;
+        }
+
+        let step = match index["step"].as_str() {
+            Some("ERROR") => "ERROR".red().bold().to_string(),
+            _ => display(&index["step"]),
+        };
+        let failed_step = match index["failed_step"].as_str() {
+            Some(failed_step) => failed_step.red().to_string(),
+            None => "-".to_string(),
+        };
+
+        builder.push_record([
+            name.to_owned(),
+            display(&index["policy"]),
+            display(&index["phase"]),
+            display(&index["action"]),
+            step,
+            display(&index["age"]),
+            failed_step,
+        ]);
+    }
+
+    print_table(builder.build(), "Index lifecycle");
+
+    // The reason of a failed step is too long for the table
+    for (name, index) in indices {
+        if let Some(reason) = index["step_info"]["reason"].as_str() {
+            print_error(format!("{}: {}", name.bold(), reason));
+        }
+    }
+}
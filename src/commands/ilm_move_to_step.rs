@@ -0,0 +1,88 @@
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::ilm::IlmMoveToStepParts;
+use serde_json::json;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_response;
+use crate::utils::output::Output;
+
+use super::ilm::explain_lifecycle;
+
+/// The current step is read from the index, the next one must exist in its policy
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the index
+    index: String,
+    /// Phase to move to (e.g. warm)
+    #[arg(long)]
+    phase: String,
+    /// Action to move to, the first action of the phase by default
+    #[arg(long)]
+    action: Option<String>,
+    /// Step to move to, the first step of the action by default
+    #[arg(long, requires = "action")]
+    step: Option<String>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+
+    let indices = explain_lifecycle(&client, &args.index).await?;
+    let current = match indices.get(&args.index) {
+        Some(index) if index["managed"] == true => index,
+        Some(_) => bail!("Index {} is not managed by a lifecycle policy", args.index),
+        None => bail!("Index {} not found", args.index),
+    };
+
+    let mut next_step = json!({ "phase": args.phase });
+    if let Some(action) = &args.action {
+        next_step["action"] = json!(action);
+    }
+    if let Some(step) = &args.step {
+        next_step["name"] = json!(step);
+    }
+
+    let body = json!({
+        "current_step": {
+            "phase": current["phase"],
+            "action": current["action"],
+            "name": current["step"]
+        },
+        "next_step": next_step
+    });
+
+    let target = [Some(&args.phase), args.action.as_ref(), args.step.as_ref()]
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+        .collect::<Vec<&str>>()
+        .join("/");
+
+    let response = client
+        .ilm()
+        .move_to_step(IlmMoveToStepParts::Index(&args.index))
+        .body(body)
+        .send()
+        .await
+        .context(format!(
+            "Request error for moving {} to another lifecycle step",
+            args.index
+        ))?;
+
+    handle_response(
+        &args.output,
+        response,
+        format!("Index {} moved to {}!", args.index.bold(), target.bold()),
+        format!("Index {} cannot be moved to {}", args.index.bold(), target),
+        args.pretty,
+    )
+    .await
+}
@@ -0,0 +1,36 @@
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+use crate::application::Application;
+
+use super::ilm_policies_delete;
+use super::ilm_policies_get;
+use super::ilm_policies_list;
+use super::ilm_policies_put;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    #[command(subcommand)]
+    sub_commands: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    /// Lists the lifecycle policies with their phases
+    List(ilm_policies_list::Arguments),
+    /// Displays the definition of a lifecycle policy
+    Get(ilm_policies_get::Arguments),
+    /// Creates or replaces a lifecycle policy from a JSON definition
+    Put(ilm_policies_put::Arguments),
+    /// Deletes a lifecycle policy
+    Delete(ilm_policies_delete::Arguments),
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    match &args.sub_commands {
+        Commands::List(args) => ilm_policies_list::handle_command(args, application).await,
+        Commands::Get(args) => ilm_policies_get::handle_command(args, application).await,
+        Commands::Put(args) => ilm_policies_put::handle_command(args, application).await,
+        Commands::Delete(args) => ilm_policies_delete::handle_command(args, application).await,
+    }
+}
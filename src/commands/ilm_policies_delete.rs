@@ -0,0 +1,45 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::ilm::IlmDeleteLifecycleParts;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_response;
+use crate::utils::output::Output;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the lifecycle policy to delete
+    name: String,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = client
+        .ilm()
+        .delete_lifecycle(IlmDeleteLifecycleParts::Policy(&args.name))
+        .send()
+        .await
+        .context(format!(
+            "Request error for deleting lifecycle policy {}",
+            args.name
+        ))?;
+
+    handle_response(
+        &args.output,
+        response,
+        format!(
+            "Lifecycle policy {} deleted successfully!",
+            args.name.bold()
+        ),
+        format!("Lifecycle policy {} cannot be deleted!", args.name.bold()),
+        args.pretty,
+    )
+    .await
+}
@@ -0,0 +1,58 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::ilm::IlmGetLifecycleParts;
+use serde_json::Value;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{output_json, output_yaml, Output};
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the lifecycle policy
+    name: String,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = client
+        .ilm()
+        .get_lifecycle(IlmGetLifecycleParts::Policy(&args.name))
+        .send()
+        .await
+        .context(format!(
+            "Request error for getting lifecycle policy {}",
+            args.name
+        ))?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            format!("Cannot get lifecycle policy {}", args.name.bold()),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for lifecycle policy")?;
+    let policy = &response_body[&args.name];
+
+    match args.output {
+        Output::Default => output_json(&policy["policy"], true)?,
+        Output::Json => output_json(policy, args.pretty)?,
+        Output::Yaml => output_yaml(policy)?,
+    }
+
+    Ok(())
+}
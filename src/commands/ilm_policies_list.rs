@@ -0,0 +1,84 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use elasticsearch::ilm::IlmGetLifecycleParts;
+use serde_json::{Map, Value};
+use tabled::builder::Builder;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{display, output_json, output_yaml, print_table, Output};
+
+/// Phases of a lifecycle policy, in their order of execution
+const PHASES: [&str; 5] = ["hot", "warm", "cold", "frozen", "delete"];
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = client
+        .ilm()
+        .get_lifecycle(IlmGetLifecycleParts::None)
+        .send()
+        .await
+        .context("Request error for listing lifecycle policies")?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            "Cannot list lifecycle policies".to_string(),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let policies: Map<String, Value> = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for lifecycle policies")?;
+
+    match args.output {
+        Output::Default => output_policies(&policies),
+        Output::Json => output_json(&policies, args.pretty)?,
+        Output::Yaml => output_yaml(&policies)?,
+    }
+
+    Ok(())
+}
+
+fn output_policies(policies: &Map<String, Value>) {
+    let mut builder = Builder::default();
+    builder.set_header(["name", "version", "phases", "indices", "modified"]);
+
+    for (name, policy) in policies {
+        let phases = &policy["policy"]["phases"];
+        let phases: Vec<String> = PHASES
+            .iter()
+            .filter_map(|phase| {
+                let min_age = phases.get(*phase)?["min_age"].as_str().unwrap_or("0ms");
+                Some(format!("{} ({})", phase, min_age))
+            })
+            .collect();
+        let indices = policy["in_use_by"]["indices"]
+            .as_array()
+            .map_or(0, Vec::len);
+
+        builder.push_record([
+            name.to_owned(),
+            display(&policy["version"]),
+            phases.join(" → "),
+            indices.to_string(),
+            display(&policy["modified_date"]),
+        ]);
+    }
+
+    print_table(builder.build(), "Lifecycle policies");
+}
@@ -0,0 +1,54 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::ilm::IlmPutLifecycleParts;
+use serde_json::json;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_response;
+use crate::utils::input::read_json_input;
+use crate::utils::output::Output;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the lifecycle policy
+    name: String,
+    /// Definition of the policy, inline JSON or path to a JSON file ("-" reads from stdin)
+    #[arg(short, long)]
+    file: String,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    // The definition can be given with or without its top-level `policy` key
+    let definition = match read_json_input(&args.file, "lifecycle policy")? {
+        definition if definition.get("policy").is_some() => definition,
+        policy => json!({ "policy": policy }),
+    };
+
+    let client = application.get_http_client()?;
+    let response = client
+        .ilm()
+        .put_lifecycle(IlmPutLifecycleParts::Policy(&args.name))
+        .body(definition)
+        .send()
+        .await
+        .context(format!(
+            "Request error for putting lifecycle policy {}",
+            args.name
+        ))?;
+
+    handle_response(
+        &args.output,
+        response,
+        format!("Lifecycle policy {} saved successfully!", args.name.bold()),
+        format!("Lifecycle policy {} cannot be saved!", args.name.bold()),
+        args.pretty,
+    )
+    .await
+}
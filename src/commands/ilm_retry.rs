@@ -0,0 +1,42 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::ilm::IlmRetryParts;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_response;
+use crate::utils::output::Output;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Indices whose failed step is retried, comma separated (supports wildcards)
+    index: String,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = client
+        .ilm()
+        .retry(IlmRetryParts::Index(&args.index))
+        .send()
+        .await
+        .context(format!(
+            "Request error for retrying lifecycle of {}",
+            args.index
+        ))?;
+
+    handle_response(
+        &args.output,
+        response,
+        format!("Lifecycle of {} retried!", args.index.bold()),
+        format!("Lifecycle of {} cannot be retried", args.index.bold()),
+        args.pretty,
+    )
+    .await
+}
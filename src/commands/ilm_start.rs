@@ -0,0 +1,35 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_response;
+use crate::utils::output::Output;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = client
+        .ilm()
+        .start()
+        .send()
+        .await
+        .context("Request error for starting lifecycle management")?;
+
+    handle_response(
+        &args.output,
+        response,
+        "Index lifecycle management started!".to_string(),
+        "Index lifecycle management cannot be started".to_string(),
+        args.pretty,
+    )
+    .await
+}
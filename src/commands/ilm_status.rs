@@ -0,0 +1,59 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use serde_json::Value;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{output_json, output_yaml, Output};
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = client
+        .ilm()
+        .get_status()
+        .send()
+        .await
+        .context("Request error for getting lifecycle management status")?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            "Cannot get lifecycle management status".to_string(),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for lifecycle management status")?;
+
+    match args.output {
+        Output::Default => {
+            let mode = response_body["operation_mode"].as_str().unwrap_or_default();
+            let mode = match mode {
+                "RUNNING" => mode.green().bold(),
+                "STOPPING" => mode.yellow().bold(),
+                _ => mode.red().bold(),
+            };
+            println!("Index lifecycle management is {}", mode);
+        }
+        Output::Json => output_json(&response_body, args.pretty)?,
+        Output::Yaml => output_yaml(&response_body)?,
+    }
+
+    Ok(())
+}
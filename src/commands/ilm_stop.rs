@@ -0,0 +1,36 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_response;
+use crate::utils::output::Output;
+
+/// Running actions complete before lifecycle management is fully stopped
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = client
+        .ilm()
+        .stop()
+        .send()
+        .await
+        .context("Request error for stopping lifecycle management")?;
+
+    handle_response(
+        &args.output,
+        response,
+        "Index lifecycle management is stopping!".to_string(),
+        "Index lifecycle management cannot be stopped".to_string(),
+        args.pretty,
+    )
+    .await
+}
@@ -0,0 +1,594 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::time::{Duration, Instant};
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, ValueEnum};
+use colored::Colorize;
+use elasticsearch::{BulkParts, Elasticsearch};
+use flate2::read::GzDecoder;
+use futures::{stream, StreamExt};
+use indicatif::{ProgressBar, ProgressStyle};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use tabled::builder::Builder;
+use tabled::settings::Style;
+
+use crate::application::Application;
+use crate::utils::output::{output_json, output_yaml, print_success, Output};
+
+/// Delay before retrying rejected documents, doubled on each attempt
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Loads documents into an index with the bulk API
+///
+/// Files are validated in a first pass before the first document is sent, while stdin is
+/// streamed and stops at the first malformed document. Lines exported by `escli export` keep
+/// their ids and routing. Documents rejected by a busy cluster (429) are retried with an
+/// exponential backoff.
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Index receiving the documents
+    index: String,
+    /// Path to the file to import, optionally gzipped ("-" reads from stdin)
+    #[arg(short, long)]
+    file: String,
+    /// Format of the file (guessed from its extension, NDJSON by default)
+    #[arg(long, value_enum)]
+    format: Option<InputFormat>,
+    /// Field used as document id, even for exported documents (ids are generated by
+    /// Elasticsearch by default)
+    #[arg(long)]
+    id_field: Option<String>,
+    /// Maximum number of documents by bulk request
+    #[arg(long, default_value_t = 1000)]
+    batch_size: usize,
+    /// Maximum size of a bulk request in megabytes
+    #[arg(long, default_value_t = 5)]
+    batch_mb: usize,
+    /// Number of bulk requests sent in parallel
+    #[arg(long, default_value_t = 4)]
+    concurrency: usize,
+    /// Number of retries for documents rejected with a 429 status
+    #[arg(long, default_value_t = 5)]
+    max_retries: u32,
+    /// Path to a NDJSON file receiving the failed documents along with their error
+    #[arg(long)]
+    dead_letter: Option<String>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum InputFormat {
+    /// One JSON document by line
+    Ndjson,
+    /// An array of JSON documents
+    Json,
+    /// A header line followed by one document by line
+    Csv,
+}
+
+#[derive(Debug)]
+struct Document {
+    id: Option<String>,
+    routing: Option<String>,
+    /// Serialized once, it is both measured for batching and sent as is
+    source: String,
+}
+
+#[derive(Debug, Default)]
+struct Batch {
+    documents: Vec<Document>,
+    bytes: usize,
+}
+
+#[derive(Debug, Default)]
+struct BatchOutcome {
+    indexed: u64,
+    retries: u64,
+    failures: Vec<(Document, Value)>,
+}
+
+#[derive(Debug, Serialize)]
+struct ImportSummary {
+    index: String,
+    indexed: u64,
+    failed: u64,
+    retries: u64,
+    batches: u64,
+    took_ms: u128,
+    docs_per_second: f64,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    // Stdin cannot be read twice, its documents are only validated while being sent
+    let total = match args.file.as_str() {
+        "-" => None,
+        _ => Some(validate_documents(args)?),
+    };
+    let documents = read_documents(args)?;
+    let client = application.get_http_client()?;
+
+    let mut dead_letter = match &args.dead_letter {
+        Some(path) => Some(BufWriter::new(
+            File::create(path).context(format!("Cannot create dead letter file at {}", path))?,
+        )),
+        None => None,
+    };
+
+    let pb = match total {
+        Some(total) => ProgressBar::new(total).with_style(
+            ProgressStyle::with_template(
+                "{spinner:.blue} {prefix} [{elapsed_precise}] [{bar:40.cyan/blue}] {human_pos}/{human_len} docs ({per_sec}, {eta}) {msg}",
+            )
+            .unwrap()
+            .progress_chars("=> "),
+        ),
+        None => ProgressBar::new_spinner().with_style(
+            ProgressStyle::with_template(
+                "{spinner:.blue} {prefix} [{elapsed_precise}] {human_pos} docs ({per_sec}) {msg}",
+            )
+            .unwrap(),
+        ),
+    };
+    pb.enable_steady_tick(Duration::from_millis(100));
+    pb.set_prefix(format!("Import into {}", args.index));
+
+    let start = Instant::now();
+    let mut summary = ImportSummary {
+        index: args.index.to_owned(),
+        indexed: 0,
+        failed: 0,
+        retries: 0,
+        batches: 0,
+        took_ms: 0,
+        docs_per_second: 0.0,
+    };
+    let mut first_error: Option<Value> = None;
+
+    let max_bytes = args.batch_mb * 1024 * 1024;
+    let mut outcomes = stream::iter(batches(documents, args.batch_size, max_bytes))
+        .map(|batch| async { send_batch(&client, args, batch?).await })
+        .buffer_unordered(args.concurrency.max(1));
+
+    while let Some(outcome) = outcomes.next().await {
+        let outcome = match outcome {
+            Ok(outcome) => outcome,
+            Err(err) => {
+                pb.finish_and_clear();
+                return Err(err.context(format!(
+                    "Import into {} stopped after {} documents",
+                    args.index, summary.indexed
+                )));
+            }
+        };
+
+        summary.batches += 1;
+        summary.indexed += outcome.indexed;
+        summary.retries += outcome.retries;
+        summary.failed += outcome.failures.len() as u64;
+        pb.inc(outcome.indexed + outcome.failures.len() as u64);
+        if summary.failed > 0 {
+            pb.set_message(format!("{} failed", summary.failed).red().to_string());
+        }
+
+        for (document, error) in outcome.failures {
+            if let Some(writer) = dead_letter.as_mut() {
+                let line = json!({
+                    "_id": document.id,
+                    "_routing": document.routing,
+                    "document": serde_json::from_str::<Value>(&document.source)?,
+                    "error": error
+                });
+                serde_json::to_writer(&mut *writer, &line)?;
+                writer.write_all(b"\n")?;
+            }
+            first_error.get_or_insert(error);
+        }
+    }
+
+    pb.finish_and_clear();
+    if let Some(writer) = dead_letter.as_mut() {
+        writer.flush().context("Cannot write dead letter file")?;
+    }
+
+    let elapsed = start.elapsed();
+    summary.took_ms = elapsed.as_millis();
+    summary.docs_per_second = match elapsed.as_secs_f64() {
+        secs if secs > 0.0 => (summary.indexed as f64 / secs * 10.0).round() / 10.0,
+        _ => 0.0,
+    };
+
+    match args.output {
+        Output::Default => {
+            if summary.failed == 0 {
+                print_success(format!(
+                    "{} documents imported into {} successfully! ({:.1}s)",
+                    summary.indexed,
+                    args.index.bold(),
+                    elapsed.as_secs_f64()
+                ));
+            }
+
+            output_import_summary(&summary);
+
+            if let Some(error) = first_error {
+                println!("First error: {}", error.to_string().red());
+                if let Some(path) = &args.dead_letter {
+                    println!("Failed documents written to {}", path.bold());
+                }
+            }
+        }
+        Output::Json => output_json(&summary, args.pretty)?,
+        Output::Yaml => output_yaml(&summary)?,
+    }
+
+    if summary.failed > 0 {
+        bail!(
+            "Import into {} finished with {} failures ({:.1}s)",
+            args.index,
+            summary.failed,
+            elapsed.as_secs_f64()
+        );
+    }
+
+    Ok(())
+}
+
+fn output_import_summary(summary: &ImportSummary) {
+    let mut builder = Builder::default();
+    builder
+        .set_header(["Indexed", "Failed", "Retries", "Batches", "Docs/s"])
+        .push_record([
+            summary.indexed.to_string(),
+            summary.failed.to_string(),
+            summary.retries.to_string(),
+            summary.batches.to_string(),
+            summary.docs_per_second.to_string(),
+        ]);
+
+    let mut table = builder.build();
+    table.with(Style::modern());
+    println!("{table}");
+}
+
+/// Sends a batch, retrying the documents rejected because the cluster is overloaded
+async fn send_batch(
+    client: &Elasticsearch,
+    args: &Arguments,
+    batch: Batch,
+) -> Result<BatchOutcome> {
+    let mut outcome = BatchOutcome::default();
+    let mut pending = batch.documents;
+    let mut backoff = INITIAL_BACKOFF;
+    let mut attempt = 0;
+
+    loop {
+        let mut body: Vec<String> = Vec::with_capacity(pending.len() * 2);
+        for document in &pending {
+            let mut action = json!({ "index": {} });
+            if let Some(id) = &document.id {
+                action["index"]["_id"] = json!(id);
+            }
+            if let Some(routing) = &document.routing {
+                action["index"]["routing"] = json!(routing);
+            }
+            body.push(action.to_string());
+            body.push(document.source.to_owned());
+        }
+
+        let response = client
+            .bulk(BulkParts::Index(&args.index))
+            .body(body)
+            .send()
+            .await
+            .context(format!("Request error for bulk import into {}", args.index))?;
+
+        let status_code = response.status_code();
+        let rejected: Vec<Document> = match status_code.as_u16() {
+            429 => pending,
+            _ if !status_code.is_success() => {
+                let response_body: Value = response.json().await.unwrap_or_default();
+                bail!(
+                    "Bulk request rejected: {}",
+                    response_body["error"]["reason"]
+                        .as_str()
+                        .unwrap_or(status_code.as_str())
+                );
+            }
+            _ => {
+                let response_body: Value = response
+                    .json()
+                    .await
+                    .context("Cannot parse JSON bulk response")?;
+                let items = response_body["items"]
+                    .as_array()
+                    .context("Missing items in bulk response")?;
+
+                let mut rejected = vec![];
+                for (document, item) in pending.into_iter().zip(items) {
+                    let result = &item["index"];
+                    match result["status"].as_u64().unwrap_or_default() {
+                        200..=299 => outcome.indexed += 1,
+                        429 => rejected.push(document),
+                        _ => outcome
+                            .failures
+                            .push((document, result["error"].to_owned())),
+                    }
+                }
+                rejected
+            }
+        };
+
+        if rejected.is_empty() {
+            return Ok(outcome);
+        }
+
+        if attempt >= args.max_retries {
+            let error = json!({
+                "type": "es_rejected_execution_exception",
+                "reason": format!("rejected after {} retries", attempt)
+            });
+            outcome.failures.extend(
+                rejected
+                    .into_iter()
+                    .map(|document| (document, error.clone())),
+            );
+            return Ok(outcome);
+        }
+
+        attempt += 1;
+        outcome.retries += rejected.len() as u64;
+        pending = rejected;
+        tokio::time::sleep(backoff).await;
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+/// Groups documents into batches limited both in count and in size, reading them as needed
+fn batches(
+    documents: impl Iterator<Item = Result<Document>>,
+    batch_size: usize,
+    max_bytes: usize,
+) -> impl Iterator<Item = Result<Batch>> {
+    let mut documents = documents.peekable();
+
+    std::iter::from_fn(move || {
+        let mut batch = Batch::default();
+
+        while batch.documents.len() < batch_size.max(1) {
+            let bytes = match documents.peek() {
+                Some(Ok(document)) => document.source.len(),
+                // Documents read before an invalid one are still sent
+                Some(Err(_)) if !batch.documents.is_empty() => break,
+                Some(Err(_)) => return documents.next().and_then(Result::err).map(Err),
+                None => break,
+            };
+
+            // A single document larger than the limit still gets its own batch
+            if !batch.documents.is_empty() && batch.bytes + bytes > max_bytes {
+                break;
+            }
+
+            if let Some(Ok(document)) = documents.next() {
+                batch.bytes += bytes;
+                batch.documents.push(document);
+            }
+        }
+
+        match batch.documents.is_empty() {
+            true => None,
+            false => Some(Ok(batch)),
+        }
+    })
+}
+
+/// Reads the whole file once without keeping the documents, returns how many it holds
+fn validate_documents(args: &Arguments) -> Result<u64> {
+    read_documents(args)?.try_fold(0, |count, document| document.map(|_| count + 1))
+}
+
+fn read_documents(args: &Arguments) -> Result<Box<dyn Iterator<Item = Result<Document>>>> {
+    let path = args.file.strip_suffix(".gz").unwrap_or(&args.file);
+    let format = match args.format {
+        Some(format) => format,
+        None if path.ends_with(".csv") => InputFormat::Csv,
+        None if path.ends_with(".json") => InputFormat::Json,
+        None => InputFormat::Ndjson,
+    };
+
+    let reader: Box<dyn Read> = match args.file.as_str() {
+        "-" => Box::new(io::stdin()),
+        file => File::open(file)
+            .map(|file| Box::new(file) as Box<dyn Read>)
+            .context(format!("Cannot open file at {}", file))?,
+    };
+    let reader: Box<dyn BufRead> = match args.file.ends_with(".gz") {
+        true => Box::new(BufReader::new(GzDecoder::new(reader))),
+        false => Box::new(BufReader::new(reader)),
+    };
+
+    let id_field = args.id_field.to_owned();
+    let to_document = move |source: Value| into_document(source, id_field.as_deref());
+
+    let documents: Box<dyn Iterator<Item = Result<Document>>> = match format {
+        InputFormat::Ndjson => Box::new(
+            reader
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| !matches!(line, Ok(line) if line.trim().is_empty()))
+                .map(move |(line_number, line)| {
+                    let line = line.context("Cannot read documents")?;
+                    let source = serde_json::from_str(&line)
+                        .context(format!("Malformated document at line {}", line_number + 1))?;
+                    to_document(source)
+                }),
+        ),
+        InputFormat::Json => {
+            let documents: Vec<Value> =
+                serde_json::from_reader(reader).context("Expecting a JSON array of documents")?;
+            Box::new(documents.into_iter().map(to_document))
+        }
+        InputFormat::Csv => {
+            let mut reader = csv::Reader::from_reader(reader);
+            let headers = reader.headers().context("Cannot read CSV header")?.clone();
+
+            Box::new(reader.into_records().enumerate().map(move |(row, record)| {
+                let record =
+                    record.context(format!("Malformated CSV record at row {}", row + 1))?;
+
+                // Values are kept as strings, Elasticsearch coerces them to the mapped types
+                let source: Map<String, Value> = headers
+                    .iter()
+                    .zip(record.iter())
+                    .filter(|(_, value)| !value.is_empty())
+                    .map(|(header, value)| (header.to_string(), json!(value)))
+                    .collect();
+                to_document(Value::Object(source))
+            }))
+        }
+    };
+
+    Ok(documents)
+}
+
+/// Documents exported by `escli export` are unwrapped from their hit envelope
+fn into_document(source: Value, id_field: Option<&str>) -> Result<Document> {
+    let (source, envelope_id, routing) = match source {
+        Value::Object(mut hit) if hit.contains_key("_source") && hit.contains_key("_id") => {
+            let id = hit
+                .remove("_id")
+                .and_then(|id| id.as_str().map(String::from));
+            let routing = hit
+                .remove("_routing")
+                .and_then(|routing| routing.as_str().map(String::from));
+            (hit.remove("_source").unwrap_or_default(), id, routing)
+        }
+        source => (source, None, None),
+    };
+
+    if !source.is_object() {
+        bail!("Documents must be JSON objects");
+    }
+
+    let id = match id_field {
+        Some(id_field) => match &source[id_field] {
+            Value::String(id) => Some(id.to_owned()),
+            Value::Number(id) => Some(id.to_string()),
+            Value::Null => bail!("Missing id field {} in document {}", id_field, source),
+            _ => bail!("Id field {} must be a string or a number", id_field),
+        },
+        None => envelope_id,
+    };
+
+    Ok(Document {
+        id,
+        routing,
+        source: source.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(bytes: usize) -> Result<Document> {
+        Ok(Document {
+            id: None,
+            routing: None,
+            source: "x".repeat(bytes),
+        })
+    }
+
+    fn batch_sizes(
+        documents: Vec<Result<Document>>,
+        batch_size: usize,
+        max_bytes: usize,
+    ) -> Vec<usize> {
+        batches(documents.into_iter(), batch_size, max_bytes)
+            .map(|batch| batch.unwrap().documents.len())
+            .collect()
+    }
+
+    #[test]
+    fn into_document_keeps_plain_sources() {
+        let document = into_document(json!({ "a": 1 }), None).unwrap();
+        assert_eq!(document.id, None);
+        assert_eq!(document.routing, None);
+        assert_eq!(document.source, r#"{"a":1}"#);
+    }
+
+    #[test]
+    fn into_document_unwraps_exported_hits() {
+        let hit = json!({ "_index": "old", "_id": "1", "_routing": "r", "_source": { "a": 1 } });
+        let document = into_document(hit, None).unwrap();
+        assert_eq!(document.id.as_deref(), Some("1"));
+        assert_eq!(document.routing.as_deref(), Some("r"));
+        assert_eq!(document.source, r#"{"a":1}"#);
+    }
+
+    #[test]
+    fn into_document_reads_the_id_field() {
+        let document = into_document(json!({ "key": "a" }), Some("key")).unwrap();
+        assert_eq!(document.id.as_deref(), Some("a"));
+
+        let document = into_document(json!({ "key": 42 }), Some("key")).unwrap();
+        assert_eq!(document.id.as_deref(), Some("42"));
+
+        assert!(into_document(json!({ "other": 1 }), Some("key")).is_err());
+        assert!(into_document(json!({ "key": [1] }), Some("key")).is_err());
+    }
+
+    #[test]
+    fn into_document_rejects_non_objects() {
+        assert!(into_document(json!([1, 2]), None).is_err());
+        assert!(into_document(json!("text"), None).is_err());
+        assert!(into_document(json!({ "_id": "1", "_source": 1 }), None).is_err());
+    }
+
+    #[test]
+    fn batches_split_on_document_count() {
+        let documents = (0..5).map(|_| document(10)).collect();
+        assert_eq!(batch_sizes(documents, 2, 1000), vec![2, 2, 1]);
+
+        let documents = (0..4).map(|_| document(10)).collect();
+        assert_eq!(batch_sizes(documents, 2, 1000), vec![2, 2]);
+
+        let documents = (0..2).map(|_| document(10)).collect();
+        assert_eq!(batch_sizes(documents, 0, 1000), vec![1, 1]);
+
+        assert!(batch_sizes(vec![], 2, 1000).is_empty());
+    }
+
+    #[test]
+    fn batches_split_on_bytes() {
+        // A batch can reach the limit exactly but not exceed it
+        let documents = (0..3).map(|_| document(50)).collect();
+        assert_eq!(batch_sizes(documents, 10, 100), vec![2, 1]);
+
+        let documents = vec![document(60), document(41), document(40)];
+        assert_eq!(batch_sizes(documents, 10, 100), vec![1, 2]);
+    }
+
+    #[test]
+    fn batches_send_oversized_documents_alone() {
+        let documents = vec![document(10), document(500), document(10)];
+        assert_eq!(batch_sizes(documents, 10, 100), vec![1, 1, 1]);
+    }
+
+    #[test]
+    fn batches_yield_valid_documents_before_an_error() {
+        let documents = vec![document(10), Err(anyhow::anyhow!("invalid")), document(10)];
+        let mut batches = batches(documents.into_iter(), 10, 1000);
+        assert_eq!(batches.next().unwrap().unwrap().documents.len(), 1);
+        assert!(batches.next().unwrap().is_err());
+        assert_eq!(batches.next().unwrap().unwrap().documents.len(), 1);
+        assert!(batches.next().is_none());
+    }
+}
@@ -4,8 +4,10 @@ use std::path::Path;
 use anyhow::{Context, Result};
 use clap::Parser;
 use colored::Colorize;
+use elasticsearch::http::response::Response;
 use elasticsearch::indices::IndicesCreateParts;
-use serde_json::json;
+use elasticsearch::Elasticsearch;
+use serde_json::{json, Value};
 
 use crate::application::Application;
 use crate::utils::handle_response::handle_response;
@@ -32,48 +34,65 @@ pub struct Arguments {
 pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
     let index_name = &args.name;
     let client = application.get_http_client()?;
-    let indices = client.indices();
-    let create = indices.create(IndicesCreateParts::Index(index_name));
 
-    let mut body = json!({});
-    if let Some(mapping_path) = &args.mapping {
-        let file = File::open(Path::new(&mapping_path)).context(format!(
-            "Cannot open mapping definition file at {}",
-            mapping_path
-        ))?;
+    let mapping = match &args.mapping {
+        Some(mapping_path) => Some(read_definition_file(mapping_path, "mapping")?),
+        None => None,
+    };
 
-        let mapping: serde_json::Value =
-            serde_json::from_reader(file).context("Malformated mapping definition")?;
+    let settings = match &args.settings {
+        Some(settings_path) => Some(read_definition_file(settings_path, "settings")?),
+        None => None,
+    };
 
-        body["mappings"] = mapping;
-    }
+    let response = create_index(&client, index_name, mapping, settings).await?;
 
-    if let Some(settings_path) = &args.settings {
-        let file = File::open(Path::new(&settings_path)).context(format!(
-            "Cannot open settings definition file at {}",
-            settings_path
-        ))?;
+    handle_response(
+        &args.output,
+        response,
+        format!("Index {} created successfully!", index_name.bold()),
+        format!("Index {} cannot be created!", index_name.bold()),
+        args.pretty,
+    )
+    .await?;
 
-        let settings: serde_json::Value =
-            serde_json::from_reader(file).context("Malformated settings definition")?;
+    Ok(())
+}
 
+/// Sends the index creation request with optional mapping and settings definitions
+pub async fn create_index(
+    client: &Elasticsearch,
+    index_name: &str,
+    mapping: Option<Value>,
+    settings: Option<Value>,
+) -> Result<Response> {
+    let mut body = json!({});
+    if let Some(mapping) = mapping {
+        body["mappings"] = mapping;
+    }
+
+    if let Some(settings) = settings {
         body["settings"] = json!({ "index": settings });
     }
 
-    let response = create
+    let response = client
+        .indices()
+        .create(IndicesCreateParts::Index(index_name))
         .body(body)
         .send()
         .await
         .context(format!("Request error for creating index {}", index_name))?;
 
-    handle_response(
-        &args.output,
-        response,
-        format!("Index {} created successfully!", index_name.bold()),
-        format!("Index {} cannot be created!", index_name.bold()),
-        args.pretty,
-    )
-    .await?;
+    Ok(response)
+}
 
-    Ok(())
+/// Reads a JSON definition file (mapping, settings...)
+pub fn read_definition_file(path: &str, kind: &str) -> Result<Value> {
+    let file = File::open(Path::new(path))
+        .context(format!("Cannot open {} definition file at {}", kind, path))?;
+
+    let definition: Value =
+        serde_json::from_reader(file).context(format!("Malformated {} definition", kind))?;
+
+    Ok(definition)
 }
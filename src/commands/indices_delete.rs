@@ -1,7 +1,8 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use clap::Parser;
 use colored::Colorize;
 use elasticsearch::indices::IndicesDeleteParts;
+use elasticsearch::Elasticsearch;
 
 use crate::application::Application;
 use crate::utils::handle_response::handle_response;
@@ -40,3 +41,20 @@ pub async fn handle_command(args: &Arguments, application: &Application) -> Resu
     )
     .await
 }
+
+/// Deletes several indices at once, failing if any of them cannot be deleted
+pub async fn delete_indices(client: &Elasticsearch, indices: &[String]) -> Result<()> {
+    let indices: Vec<&str> = indices.iter().map(String::as_str).collect();
+    let response = client
+        .indices()
+        .delete(IndicesDeleteParts::Index(&indices))
+        .send()
+        .await
+        .context(format!("Request error for deleting {}", indices.join(",")))?;
+
+    if !response.status_code().is_success() {
+        bail!("Indices {} cannot be deleted", indices.join(","));
+    }
+
+    Ok(())
+}
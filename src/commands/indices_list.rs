@@ -8,12 +8,14 @@ use futures::{stream, StreamExt};
 use log::debug;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use tabled::settings::object::Rows;
-use tabled::settings::{Format, Modify, Panel, Style};
+use tabled::settings::locator::ByColumnName;
+use tabled::settings::Disable;
 use tabled::{Table, Tabled};
 
 use crate::application::Application;
-use crate::utils::output::{output_json, Output};
+use crate::utils::output::{output_json, output_yaml, print_table, Output};
+
+use super::ilm::explain_lifecycle;
 
 #[derive(Debug, Deserialize, Serialize, Tabled)]
 pub struct Index {
@@ -32,6 +34,9 @@ pub struct Index {
     docs_count: Option<String>,
     #[tabled(display_with = "display_option")]
     version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[tabled(display_with = "display_option")]
+    ilm_phase: Option<String>,
 }
 
 impl Index {
@@ -55,6 +60,10 @@ fn display_option(o: &Option<String>) -> String {
 }
 #[derive(Parser, Debug)]
 pub struct Arguments {
+    /// Shows the lifecycle phase of each index
+    #[arg(long, default_value_t = false)]
+    ilm: bool,
+
     /// Output format
     #[arg(short, long, value_enum, default_value_t = Output::Default)]
     output: Output,
@@ -89,25 +98,48 @@ pub async fn handle_command(args: &Arguments, application: &Application) -> Resu
         .await
         .context("Cannot parse JSON response for indices list")?;
 
-    let indices: Vec<Index> = stream::iter(indices)
+    let mut indices: Vec<Index> = stream::iter(indices)
         .map(|index| add_version_to_index(index, client.clone()))
         .buffer_unordered(50)
         .collect()
         .await;
 
+    if args.ilm {
+        // A single explain request covers every index, unmanaged ones have no phase. Hidden
+        // indices such as the backing indices of data streams are only matched by `.*`
+        // ILM may be unavailable or some indices not readable, the phase is then left empty
+        let lifecycles = match explain_lifecycle(&client, "*,.*").await {
+            Ok(lifecycles) => lifecycles,
+            Err(err) => {
+                eprintln!(
+                    "{} cannot get the lifecycle phases: {:#}",
+                    "Warning:".yellow().bold(),
+                    err
+                );
+                Default::default()
+            }
+        };
+        for index in indices.iter_mut() {
+            index.ilm_phase = lifecycles
+                .get(&index.name)
+                .and_then(|lifecycle| lifecycle["phase"].as_str())
+                .map(str::to_string);
+        }
+    }
+
     match args.output {
         Output::Default => {
-            let header_format = Format::content(|s| s.bold().to_string());
-
             let mut table = Table::new(indices);
-            table
-                .with(Style::modern())
-                .with(Panel::header("Indices".bold().to_string()))
-                .with(Modify::new(Rows::single(1)).with(header_format));
 
-            println!("{table}");
+            // Column names are looked up in the first row, before the panel is added
+            if !args.ilm {
+                table.with(Disable::column(ByColumnName::new("ilm_phase")));
+            }
+
+            print_table(table, "Indices");
         }
         Output::Json => output_json(&indices, args.pretty)?,
+        Output::Yaml => output_yaml(&indices)?,
     };
 
     Ok(())
@@ -4,6 +4,7 @@ use clap::{Parser, Subcommand};
 use crate::application::Application;
 
 use super::mappings_get;
+use super::mappings_infer;
 
 #[derive(Debug, Parser)]
 pub struct Arguments {
@@ -15,10 +16,13 @@ pub struct Arguments {
 enum Commands {
     /// Retrieves mapping definitions for one index
     Get(mappings_get::Arguments),
+    /// Proposes a mapping definition from sample documents
+    Infer(mappings_infer::Arguments),
 }
 
 pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
     match &args.sub_commands {
         Commands::Get(args) => mappings_get::handle_command(args, application).await,
+        Commands::Infer(args) => mappings_infer::handle_command(args, application).await,
     }
 }
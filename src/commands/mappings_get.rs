@@ -6,7 +6,7 @@ use serde_json::Value;
 
 use crate::application::Application;
 use crate::utils::handle_response::handle_error_response;
-use crate::utils::output::{output_json, Output};
+use crate::utils::output::{output_json, output_yaml, Output};
 
 #[derive(Debug, Parser)]
 pub struct Arguments {
@@ -46,7 +46,10 @@ pub async fn handle_command(args: &Arguments, application: &Application) -> Resu
     }
 
     let response_body: Value = response.json().await?;
-    output_json(&response_body, args.pretty)?;
+    match args.output {
+        Output::Default | Output::Json => output_json(&response_body, args.pretty)?,
+        Output::Yaml => output_yaml(&response_body)?,
+    }
 
     Ok(())
 }
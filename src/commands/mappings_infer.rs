@@ -0,0 +1,570 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use clap::Parser;
+use colored::Colorize;
+use serde_json::{json, Map, Value};
+use tabled::{Table, Tabled};
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_response;
+use crate::utils::output::{output_json, output_yaml, print_table, Output};
+
+use super::indices_create::{create_index, read_definition_file};
+
+/// Strings longer than this are considered full text
+const KEYWORD_MAX_LENGTH: usize = 256;
+
+/// Field name words hinting that a pair of coordinates is a geo point
+const GEO_FIELD_HINTS: [&str; 7] = [
+    "geo",
+    "location",
+    "coordinates",
+    "coords",
+    "position",
+    "latlon",
+    "point",
+];
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Path to a NDJSON file containing sample documents ("-" reads from stdin)
+    #[arg(short, long)]
+    from: String,
+    /// Creates this index with the inferred mapping
+    #[arg(short, long)]
+    apply: Option<String>,
+    /// Path to a settings definition file in JSON format (used with --apply)
+    #[arg(short, long, requires = "apply")]
+    settings: Option<String>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum FieldType {
+    Boolean,
+    Long,
+    Double,
+    Date,
+    GeoPoint,
+    Object,
+    Nested,
+    Keyword,
+    Text,
+}
+
+impl FieldType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            FieldType::Boolean => "boolean",
+            FieldType::Long => "long",
+            FieldType::Double => "double",
+            FieldType::Date => "date",
+            FieldType::GeoPoint => "geo_point",
+            FieldType::Object => "object",
+            FieldType::Nested => "nested",
+            FieldType::Keyword => "keyword",
+            FieldType::Text => "text",
+        }
+    }
+}
+
+/// Types observed for a field and its sub-fields across all sample documents
+#[derive(Debug, Default)]
+struct FieldNode {
+    types: BTreeMap<FieldType, usize>,
+    children: BTreeMap<String, FieldNode>,
+}
+
+impl FieldNode {
+    fn observe_document(&mut self, document: &Map<String, Value>) {
+        for (name, value) in document {
+            self.children
+                .entry(name.to_owned())
+                .or_default()
+                .observe(name, value, false);
+        }
+    }
+
+    fn observe(&mut self, name: &str, value: &Value, in_array: bool) {
+        let field_type = match value {
+            Value::Null => return,
+            Value::Bool(_) => FieldType::Boolean,
+            Value::Number(n) if n.is_f64() => FieldType::Double,
+            Value::Number(_) => FieldType::Long,
+            Value::String(s) => infer_string_type(name, s),
+            Value::Array(items) if is_geo_array(name, items) => FieldType::GeoPoint,
+            Value::Array(items) => {
+                for item in items {
+                    self.observe(name, item, true);
+                }
+                return;
+            }
+            Value::Object(map) if is_geo_object(map) => FieldType::GeoPoint,
+            Value::Object(map) => {
+                self.observe_document(map);
+                match in_array {
+                    true => FieldType::Nested,
+                    false => FieldType::Object,
+                }
+            }
+        };
+
+        *self.types.entry(field_type).or_default() += 1;
+    }
+
+    fn count(&self, field_type: FieldType) -> usize {
+        self.types.get(&field_type).copied().unwrap_or(0)
+    }
+
+    fn observations(&self) -> usize {
+        self.types.values().sum()
+    }
+
+    /// Picks the type matching most observations, along with the number of matching observations.
+    /// Compatible types are merged (long + double, keyword + text, object + nested).
+    fn resolve(&self) -> (FieldType, usize) {
+        let pick = |wider: FieldType, narrower: FieldType| match self.count(wider) {
+            0 => narrower,
+            _ => wider,
+        };
+
+        // On ties, the last candidate wins: strings are the most permissive choice
+        let candidates = [
+            (FieldType::Boolean, self.count(FieldType::Boolean)),
+            (
+                pick(FieldType::Double, FieldType::Long),
+                self.count(FieldType::Double) + self.count(FieldType::Long),
+            ),
+            (FieldType::Date, self.count(FieldType::Date)),
+            (FieldType::GeoPoint, self.count(FieldType::GeoPoint)),
+            (
+                pick(FieldType::Nested, FieldType::Object),
+                self.count(FieldType::Nested) + self.count(FieldType::Object),
+            ),
+            (
+                pick(FieldType::Text, FieldType::Keyword),
+                self.count(FieldType::Text) + self.count(FieldType::Keyword),
+            ),
+        ];
+
+        candidates
+            .into_iter()
+            .max_by_key(|(_, count)| *count)
+            .unwrap_or((FieldType::Keyword, 0))
+    }
+
+    fn properties(&self) -> Value {
+        let properties: Map<String, Value> = self
+            .children
+            .iter()
+            .filter(|(_, child)| child.observations() > 0)
+            .map(|(name, child)| (name.to_owned(), child.mapping()))
+            .collect();
+
+        Value::Object(properties)
+    }
+
+    fn mapping(&self) -> Value {
+        match self.resolve().0 {
+            FieldType::Text => json!({
+                "type": "text",
+                "fields": {
+                    "keyword": {
+                        "type": "keyword",
+                        "ignore_above": KEYWORD_MAX_LENGTH
+                    }
+                }
+            }),
+            FieldType::Object => json!({ "properties": self.properties() }),
+            FieldType::Nested => json!({ "type": "nested", "properties": self.properties() }),
+            field_type => json!({ "type": field_type.as_str() }),
+        }
+    }
+
+    fn report(&self, path: &str, report: &mut Vec<InferredField>) {
+        for (name, child) in &self.children {
+            let field = match path {
+                "" => name.to_owned(),
+                _ => format!("{}.{}", path, name),
+            };
+
+            let observations = child.observations();
+            if observations == 0 {
+                continue;
+            }
+
+            let (field_type, matching) = child.resolve();
+            let observed = child
+                .types
+                .iter()
+                .map(|(t, count)| format!("{} ({})", t.as_str(), count))
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            report.push(InferredField {
+                field: field.clone(),
+                field_type: field_type.as_str().to_string(),
+                confidence: format!("{:.1}%", matching as f64 * 100.0 / observations as f64),
+                observed,
+            });
+
+            child.report(&field, report);
+        }
+    }
+}
+
+#[derive(Debug, Tabled)]
+struct InferredField {
+    #[tabled(rename = "Field")]
+    field: String,
+    #[tabled(rename = "Type")]
+    field_type: String,
+    #[tabled(rename = "Confidence")]
+    confidence: String,
+    #[tabled(rename = "Observed types")]
+    observed: String,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let (root, documents_count) = read_sample_documents(&args.from)?;
+    if documents_count == 0 {
+        bail!("No sample documents found in {}", args.from);
+    }
+
+    let mapping = json!({ "properties": root.properties() });
+    let mut report = Vec::new();
+    root.report("", &mut report);
+
+    if let Output::Default = args.output {
+        print_table(
+            Table::new(&report),
+            &format!("Inferred fields ({} documents)", documents_count),
+        );
+    }
+
+    match &args.apply {
+        Some(index_name) => {
+            let settings = match &args.settings {
+                Some(settings_path) => Some(read_definition_file(settings_path, "settings")?),
+                None => None,
+            };
+
+            let client = application.get_http_client()?;
+            let response = create_index(&client, index_name, Some(mapping), settings).await?;
+
+            handle_response(
+                &args.output,
+                response,
+                format!(
+                    "Index {} created successfully with the inferred mapping!",
+                    index_name.bold()
+                ),
+                format!("Index {} cannot be created!", index_name.bold()),
+                args.pretty,
+            )
+            .await?;
+        }
+        None => match args.output {
+            Output::Default => output_json(&mapping, true)?,
+            Output::Json => output_json(&mapping, args.pretty)?,
+            Output::Yaml => output_yaml(&mapping)?,
+        },
+    }
+
+    Ok(())
+}
+
+/// Reads NDJSON sample documents and records the types observed for each field
+fn read_sample_documents(path: &str) -> Result<(FieldNode, usize)> {
+    let reader: Box<dyn BufRead> = match path {
+        "-" => Box::new(BufReader::new(io::stdin())),
+        _ => Box::new(BufReader::new(
+            File::open(path).context(format!("Cannot open sample documents file at {}", path))?,
+        )),
+    };
+
+    let mut root = FieldNode::default();
+    let mut documents_count = 0;
+
+    for (line_number, line) in reader.lines().enumerate() {
+        let line = line.context(format!("Cannot read sample documents from {}", path))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let document: Value = serde_json::from_str(&line)
+            .context(format!("Malformated document at line {}", line_number + 1))?;
+
+        match document {
+            Value::Object(document) => root.observe_document(&document),
+            _ => bail!("Document at line {} is not a JSON object", line_number + 1),
+        }
+
+        documents_count += 1;
+    }
+
+    Ok((root, documents_count))
+}
+
+fn infer_string_type(name: &str, value: &str) -> FieldType {
+    if is_date(value) {
+        return FieldType::Date;
+    }
+
+    if has_geo_hint(name) && parse_lat_lon(value).is_some() {
+        return FieldType::GeoPoint;
+    }
+
+    if value.len() > KEYWORD_MAX_LENGTH || value.contains(char::is_whitespace) {
+        FieldType::Text
+    } else {
+        FieldType::Keyword
+    }
+}
+
+/// Matches a valid `yyyy-MM-dd` date optionally followed by a time and a timezone offset
+fn is_date(value: &str) -> bool {
+    const DATE_TIME_FORMATS: [&str; 4] = [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M",
+    ];
+
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok()
+        || DateTime::parse_from_rfc3339(value).is_ok()
+        || DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z").is_ok()
+        || DATE_TIME_FORMATS
+            .iter()
+            .any(|format| NaiveDateTime::parse_from_str(value, format).is_ok())
+}
+
+/// Matches whole words of the field name, so `endpoint` or `geography` are not locations
+fn has_geo_hint(name: &str) -> bool {
+    let words = split_words(name);
+    let joined = words.concat();
+
+    GEO_FIELD_HINTS
+        .iter()
+        .any(|hint| joined == *hint || words.iter().any(|word| word == hint))
+}
+
+/// Splits a field name on separators and camelCase boundaries, in lowercase
+fn split_words(name: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut previous_is_lowercase = false;
+
+    for c in name.chars() {
+        if !c.is_alphanumeric() {
+            previous_is_lowercase = false;
+            words.push(String::new());
+            continue;
+        }
+
+        if (c.is_uppercase() && previous_is_lowercase) || words.is_empty() {
+            words.push(String::new());
+        }
+        previous_is_lowercase = c.is_lowercase() || c.is_ascii_digit();
+
+        if let Some(word) = words.last_mut() {
+            word.extend(c.to_lowercase());
+        }
+    }
+
+    words.retain(|word| !word.is_empty());
+    words
+}
+
+/// Parses a `"lat,lon"` string
+fn parse_lat_lon(value: &str) -> Option<(f64, f64)> {
+    let (lat, lon) = value.split_once(',')?;
+    let lat: f64 = lat.trim().parse().ok()?;
+    let lon: f64 = lon.trim().parse().ok()?;
+    is_valid_coordinates(lat, lon).then_some((lat, lon))
+}
+
+fn is_valid_coordinates(lat: f64, lon: f64) -> bool {
+    (-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon)
+}
+
+/// Matches `{ "lat": 41.12, "lon": -71.34 }`
+fn is_geo_object(map: &Map<String, Value>) -> bool {
+    if map.len() != 2 {
+        return false;
+    }
+
+    match (
+        map.get("lat").and_then(Value::as_f64),
+        map.get("lon").and_then(Value::as_f64),
+    ) {
+        (Some(lat), Some(lon)) => is_valid_coordinates(lat, lon),
+        _ => false,
+    }
+}
+
+/// Matches `[lon, lat]` when the field name looks like a location
+fn is_geo_array(name: &str, items: &[Value]) -> bool {
+    if items.len() != 2 || !has_geo_hint(name) {
+        return false;
+    }
+
+    match (items[0].as_f64(), items[1].as_f64()) {
+        (Some(lon), Some(lat)) => is_valid_coordinates(lat, lon),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn infer(documents: &[Value]) -> FieldNode {
+        let mut root = FieldNode::default();
+        for document in documents {
+            root.observe_document(document.as_object().unwrap());
+        }
+        root
+    }
+
+    #[test]
+    fn is_date_accepts_valid_dates() {
+        assert!(is_date("2023-04-30"));
+        assert!(is_date("2023-04-30T12:34:56"));
+        assert!(is_date("2023-04-30T12:34:56.789Z"));
+        assert!(is_date("2023-04-30T12:34:56+02:00"));
+        assert!(is_date("2023-04-30T12:34:56.789+0200"));
+        assert!(is_date("2023-04-30 12:34:56"));
+        assert!(is_date("2023-04-30T12:34"));
+    }
+
+    #[test]
+    fn is_date_rejects_impossible_dates() {
+        assert!(!is_date("2023-13-01"));
+        assert!(!is_date("2023-02-30"));
+        assert!(!is_date("2023-04-31"));
+        assert!(!is_date("2023-04-30T25:00:00"));
+        assert!(!is_date("2023-04-30Tfoo"));
+        assert!(!is_date("20230430"));
+        assert!(!is_date("hello"));
+    }
+
+    #[test]
+    fn has_geo_hint_matches_whole_words() {
+        assert!(has_geo_hint("location"));
+        assert!(has_geo_hint("geo"));
+        assert!(has_geo_hint("pickup_location"));
+        assert!(has_geo_hint("startPosition"));
+        assert!(has_geo_hint("GeoPoint"));
+        assert!(has_geo_hint("latlon"));
+        assert!(has_geo_hint("latLon"));
+        assert!(has_geo_hint("lat_lon"));
+        assert!(!has_geo_hint("endpoint"));
+        assert!(!has_geo_hint("geography"));
+        assert!(!has_geo_hint("relocation"));
+        assert!(!has_geo_hint("disposition"));
+    }
+
+    #[test]
+    fn parse_lat_lon_checks_ranges() {
+        assert_eq!(parse_lat_lon("41.12, -71.34"), Some((41.12, -71.34)));
+        assert_eq!(parse_lat_lon("91.0,0"), None);
+        assert_eq!(parse_lat_lon("0,181"), None);
+        assert_eq!(parse_lat_lon("a,b"), None);
+    }
+
+    #[test]
+    fn infer_string_type_detects_each_kind() {
+        assert_eq!(infer_string_type("created", "2023-04-30"), FieldType::Date);
+        assert_eq!(
+            infer_string_type("location", "41.12,-71.34"),
+            FieldType::GeoPoint
+        );
+        assert_eq!(
+            infer_string_type("endpoint", "41.12,-71.34"),
+            FieldType::Keyword
+        );
+        assert_eq!(infer_string_type("status", "active"), FieldType::Keyword);
+        assert_eq!(infer_string_type("title", "hello world"), FieldType::Text);
+        assert_eq!(
+            infer_string_type("id", &"a".repeat(KEYWORD_MAX_LENGTH + 1)),
+            FieldType::Text
+        );
+    }
+
+    #[test]
+    fn geo_points_are_detected_from_objects_and_arrays() {
+        let root = infer(&[json!({
+            "pin": { "lat": 41.12, "lon": -71.34 },
+            "coordinates": [-71.34, 41.12],
+            "sizes": [1, 2]
+        })]);
+
+        assert_eq!(root.children["pin"].resolve().0, FieldType::GeoPoint);
+        assert_eq!(
+            root.children["coordinates"].resolve().0,
+            FieldType::GeoPoint
+        );
+        assert_eq!(root.children["sizes"].resolve().0, FieldType::Long);
+    }
+
+    #[test]
+    fn resolve_merges_compatible_types() {
+        let root = infer(&[
+            json!({ "price": 1, "name": "foo", "tags": [{ "a": 1 }] }),
+            json!({ "price": 1.5, "name": "foo bar", "tags": { "a": 2 } }),
+            json!({ "price": 2, "name": "baz" }),
+        ]);
+
+        assert_eq!(root.children["price"].resolve(), (FieldType::Double, 3));
+        assert_eq!(root.children["name"].resolve(), (FieldType::Text, 3));
+        assert_eq!(root.children["tags"].resolve(), (FieldType::Nested, 2));
+    }
+
+    #[test]
+    fn resolve_picks_the_majority_type() {
+        let root = infer(&[
+            json!({ "flag": true }),
+            json!({ "flag": false }),
+            json!({ "flag": "maybe" }),
+        ]);
+
+        assert_eq!(root.children["flag"].resolve(), (FieldType::Boolean, 2));
+    }
+
+    #[test]
+    fn mapping_builds_sub_properties() {
+        let root = infer(&[json!({
+            "user": { "name": "John Doe", "age": 42 },
+            "missing": null
+        })]);
+
+        assert_eq!(
+            root.properties(),
+            json!({
+                "user": {
+                    "properties": {
+                        "age": { "type": "long" },
+                        "name": {
+                            "type": "text",
+                            "fields": {
+                                "keyword": {
+                                    "type": "keyword",
+                                    "ignore_above": KEYWORD_MAX_LENGTH
+                                }
+                            }
+                        }
+                    }
+                }
+            })
+        );
+    }
+}
@@ -0,0 +1,602 @@
+use std::fmt;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::indices::{IndicesExistsParts, IndicesRefreshParts};
+use elasticsearch::params::GroupBy;
+use elasticsearch::tasks::TasksCancelParts;
+use elasticsearch::Elasticsearch;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::application::Application;
+use crate::utils::output::{output_json, output_yaml, print_error, print_success, Output};
+
+use super::aliases_list::{get_alias_definitions, get_alias_indices};
+use super::aliases_swap::{check_alias_name, inherited_options, swap_alias};
+use super::count::count_documents;
+use super::indices_create::{create_index, read_definition_file};
+use super::indices_delete::delete_indices;
+use super::reindex::{check_task_outcome, poll_task, PollArguments};
+
+/// Moves an alias to a new index created from a mapping, without downtime
+///
+/// The new index is deleted if a step fails for good (index rejected, reindex failed, documents
+/// count mismatch). On other errors or when escli is interrupted, running the same command
+/// again resumes the migration where it stopped, --abort rolls it back.
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Alias to migrate
+    alias: String,
+    /// Path to the mapping definition of the new index in JSON format
+    #[arg(short, long, required_unless_present = "abort")]
+    mapping: Option<String>,
+    /// Path to the settings definition of the new index in JSON format
+    #[arg(short, long)]
+    settings: Option<String>,
+    /// Name of the new index (default is <alias>-<timestamp>)
+    #[arg(short, long)]
+    new_index: Option<String>,
+    /// Deletes the previous indices once the alias is swapped
+    #[arg(long, default_value_t = false)]
+    delete_old: bool,
+    /// Rolls back an interrupted migration instead of resuming it
+    #[arg(long, default_value_t = false)]
+    abort: bool,
+    #[command(flatten)]
+    poll: PollArguments,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+/// Last completed step of a migration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Step {
+    Pending,
+    Created,
+    ReindexRequested,
+    Reindexing,
+    Reindexed,
+    Verified,
+    Swapped,
+}
+
+/// Migration progress, saved locally after each step
+#[derive(Debug, Serialize, Deserialize)]
+struct Migration {
+    alias: String,
+    old_indices: Vec<String>,
+    new_index: String,
+    mapping: Value,
+    settings: Option<Value>,
+    step: Step,
+    task_id: Option<String>,
+    docs_count: Option<u64>,
+}
+
+/// A step failure that retrying would not fix, the migration is rolled back
+#[derive(Debug)]
+struct StepFailed(String);
+
+impl fmt::Display for StepFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for StepFailed {}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let state_path = get_state_path(application.cluster_name(), &args.alias)?;
+
+    let mut migration = match load_state(&state_path)? {
+        Some(migration) => {
+            if args.abort {
+                rollback(&client, &migration, &state_path).await?;
+                print_success(format!(
+                    "Migration of alias {} aborted!",
+                    migration.alias.bold()
+                ));
+                return Ok(());
+            }
+
+            check_resume_arguments(args, &migration)?;
+            log_step(
+                args,
+                format!(
+                    "Resuming migration of alias {} to {} (last step: {:?})",
+                    migration.alias.bold(),
+                    migration.new_index.bold(),
+                    migration.step
+                ),
+            );
+            migration
+        }
+        None => {
+            if args.abort {
+                bail!("No migration in progress for alias {}", args.alias);
+            }
+
+            check_alias_name(&args.alias)?;
+
+            let mapping_path = args.mapping.as_deref().context("Missing mapping file")?;
+            let mapping = read_definition_file(mapping_path, "mapping")?;
+            let settings = match &args.settings {
+                Some(settings_path) => Some(read_definition_file(settings_path, "settings")?),
+                None => None,
+            };
+
+            let old_indices = get_alias_indices(&client, &args.alias).await?;
+            let new_index = match &args.new_index {
+                Some(new_index) => new_index.to_owned(),
+                None => format!(
+                    "{}-{}",
+                    args.alias,
+                    SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs()
+                ),
+            };
+
+            if old_indices.contains(&new_index) {
+                bail!("Alias {} already points to {}", args.alias, new_index);
+            }
+
+            // The new index is then known to be created by the migration, and safe to roll back
+            if index_exists(&client, &new_index).await? {
+                bail!("Index {} already exists", new_index);
+            }
+
+            let migration = Migration {
+                alias: args.alias.to_owned(),
+                old_indices,
+                new_index,
+                mapping,
+                settings,
+                step: Step::Pending,
+                task_id: None,
+                docs_count: None,
+            };
+            save_state(&state_path, &migration)?;
+            migration
+        }
+    };
+
+    if let Err(err) = run_migration(&client, &mut migration, args, &state_path).await {
+        if migration.step >= Step::Swapped {
+            fs::remove_file(&state_path).ok();
+            return Err(err);
+        }
+
+        if err.downcast_ref::<StepFailed>().is_none() {
+            return Err(err.context(format!(
+                "Migration of alias {} interrupted, run the same command again to resume it or with --abort to roll it back",
+                args.alias
+            )));
+        }
+
+        print_error(format!("Migration of alias {} failed!", args.alias.bold()));
+        return match rollback(&client, &migration, &state_path).await? {
+            true => Err(err.context(format!(
+                "Migration rolled back, index {} deleted",
+                migration.new_index
+            ))),
+            false => Err(err.context("Migration rolled back")),
+        };
+    }
+
+    fs::remove_file(&state_path).ok();
+
+    match args.output {
+        Output::Default => print_success(format!(
+            "Alias {} migrated to {} successfully!",
+            migration.alias.bold(),
+            migration.new_index.bold()
+        )),
+        Output::Json => output_json(&migration, args.pretty)?,
+        Output::Yaml => output_yaml(&migration)?,
+    }
+
+    Ok(())
+}
+
+/// A resumed migration must target the same index with the same definitions
+fn check_resume_arguments(args: &Arguments, migration: &Migration) -> Result<()> {
+    let abort_hint = "run the command with --abort first to start a new migration";
+
+    if let Some(new_index) = &args.new_index {
+        if *new_index != migration.new_index {
+            bail!(
+                "Migration of alias {} in progress targets {}, not {}: {}",
+                migration.alias,
+                migration.new_index,
+                new_index,
+                abort_hint
+            );
+        }
+    }
+
+    if let Some(mapping_path) = &args.mapping {
+        if read_definition_file(mapping_path, "mapping")? != migration.mapping {
+            bail!(
+                "Mapping in {} differs from the one of the migration in progress: {}",
+                mapping_path,
+                abort_hint
+            );
+        }
+    }
+
+    let settings = match &args.settings {
+        Some(settings_path) => Some(read_definition_file(settings_path, "settings")?),
+        None => None,
+    };
+    if settings != migration.settings {
+        bail!(
+            "Settings differ from the ones of the migration in progress: {}",
+            abort_hint
+        );
+    }
+
+    Ok(())
+}
+
+async fn run_migration(
+    client: &Elasticsearch,
+    migration: &mut Migration,
+    args: &Arguments,
+    state_path: &Path,
+) -> Result<()> {
+    loop {
+        match migration.step {
+            Step::Pending => {
+                // The index may have been created right before an interruption
+                if !index_exists(client, &migration.new_index).await? {
+                    let response = create_index(
+                        client,
+                        &migration.new_index,
+                        Some(migration.mapping.clone()),
+                        migration.settings.clone(),
+                    )
+                    .await?;
+
+                    let status_code = response.status_code();
+                    if !status_code.is_success() {
+                        let error: Value = response.json().await.unwrap_or_default();
+                        let msg = format!(
+                            "Index {} cannot be created: {}",
+                            migration.new_index, error["error"]["reason"]
+                        );
+                        return Err(match status_code.is_client_error() {
+                            true => StepFailed(msg).into(),
+                            false => anyhow!(msg),
+                        });
+                    }
+                }
+
+                advance(migration, Step::Created, state_path)?;
+                log_step(
+                    args,
+                    format!("Index {} created", migration.new_index.bold()),
+                );
+            }
+            Step::Created => {
+                // Saved before submitting the reindex, so a resume looks for it instead of
+                // starting another one into the same index
+                advance(migration, Step::ReindexRequested, state_path)?;
+            }
+            Step::ReindexRequested => {
+                let task_id = match find_reindex_task(client, &migration.new_index).await? {
+                    Some(task_id) => {
+                        log_step(
+                            args,
+                            format!("Reattached to running reindex task {}", task_id.bold()),
+                        );
+                        task_id
+                    }
+                    None => start_reindex(client, migration).await?,
+                };
+                migration.task_id = Some(task_id);
+                advance(migration, Step::Reindexing, state_path)?;
+            }
+            Step::Reindexing => {
+                let task_id = migration
+                    .task_id
+                    .as_deref()
+                    .context("Missing reindex task id in migration state")?;
+                let task = poll_task(task_id, client, "Reindex", args.poll.interval()).await?;
+                check_task_outcome(&task, "Reindex")
+                    .map_err(|err| StepFailed(format!("{:#}", err)))?;
+
+                advance(migration, Step::Reindexed, state_path)?;
+                log_step(
+                    args,
+                    format!(
+                        "Documents reindexed from {} to {}",
+                        migration.old_indices.join(",").bold(),
+                        migration.new_index.bold()
+                    ),
+                );
+            }
+            Step::Reindexed => {
+                migration.docs_count = Some(verify_counts(client, migration).await?);
+                advance(migration, Step::Verified, state_path)?;
+                log_step(
+                    args,
+                    format!(
+                        "Documents count verified ({} documents)",
+                        migration.docs_count.unwrap_or(0)
+                    ),
+                );
+            }
+            Step::Verified => {
+                let definitions = get_alias_definitions(client, &migration.alias).await?;
+                let response = swap_alias(
+                    client,
+                    &migration.alias,
+                    &migration.old_indices,
+                    &migration.new_index,
+                    &inherited_options(&definitions, &migration.new_index),
+                )
+                .await?;
+
+                let status_code = response.status_code();
+                if !status_code.is_success() {
+                    let msg = format!(
+                        "Alias {} cannot be swapped to {}",
+                        migration.alias, migration.new_index
+                    );
+                    return Err(match status_code.is_client_error() {
+                        true => StepFailed(msg).into(),
+                        false => anyhow!(msg),
+                    });
+                }
+
+                advance(migration, Step::Swapped, state_path)?;
+                log_step(
+                    args,
+                    format!(
+                        "Alias {} swapped to {}",
+                        migration.alias.bold(),
+                        migration.new_index.bold()
+                    ),
+                );
+            }
+            Step::Swapped => {
+                if args.delete_old && !migration.old_indices.is_empty() {
+                    delete_indices(client, &migration.old_indices).await?;
+                    log_step(
+                        args,
+                        format!(
+                            "Old indices {} deleted",
+                            migration.old_indices.join(",").bold()
+                        ),
+                    );
+                }
+
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Saves the migration as soon as a step is completed, so it never runs twice
+fn advance(migration: &mut Migration, step: Step, state_path: &Path) -> Result<()> {
+    migration.step = step;
+    save_state(state_path, migration)
+}
+
+async fn start_reindex(client: &Elasticsearch, migration: &Migration) -> Result<String> {
+    let response = client
+        .reindex()
+        .wait_for_completion(false)
+        .body(json!({
+            "source": {
+                "index": migration.old_indices
+            },
+            "dest": {
+                "index": migration.new_index
+            }
+        }))
+        .send()
+        .await
+        .context(format!(
+            "Request error for reindex from {} to {}",
+            migration.old_indices.join(","),
+            migration.new_index
+        ))?;
+
+    let status_code = response.status_code();
+    if !status_code.is_success() {
+        let msg = format!("Reindex to {} cannot be started", migration.new_index);
+        return Err(match status_code.is_client_error() {
+            true => StepFailed(msg).into(),
+            false => anyhow!(msg),
+        });
+    }
+
+    let response_body: Value = response.json().await?;
+    match response_body.get("task").and_then(Value::as_str) {
+        Some(task_id) => Ok(task_id.to_string()),
+        None => bail!("Missing task in reindex response"),
+    }
+}
+
+/// Id of a running reindex into the index, started by an interrupted migration
+async fn find_reindex_task(client: &Elasticsearch, index: &str) -> Result<Option<String>> {
+    let response = client
+        .tasks()
+        .list()
+        .actions(&["*reindex"])
+        .detailed(true)
+        .group_by(GroupBy::None)
+        .send()
+        .await
+        .context("Request error for listing reindex tasks")?;
+
+    if !response.status_code().is_success() {
+        bail!("Cannot list reindex tasks: {}", response.status_code());
+    }
+
+    let response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for tasks")?;
+
+    // Reindex descriptions end with the destination, e.g. "reindex from [old] to [new]"
+    let destination = format!(" to [{}]", index);
+    let task_id = response_body["tasks"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|task| task.get("parent_task_id").is_none())
+        .find(|task| {
+            task["description"]
+                .as_str()
+                .unwrap_or_default()
+                .ends_with(&destination)
+        })
+        .and_then(|task| {
+            Some(format!(
+                "{}:{}",
+                task["node"].as_str()?,
+                task["id"].as_u64()?
+            ))
+        });
+
+    Ok(task_id)
+}
+
+async fn verify_counts(client: &Elasticsearch, migration: &Migration) -> Result<u64> {
+    client
+        .indices()
+        .refresh(IndicesRefreshParts::Index(&[&migration.new_index]))
+        .send()
+        .await
+        .context(format!(
+            "Request error for refreshing index {}",
+            migration.new_index
+        ))?;
+
+    let old_indices: Vec<&str> = migration.old_indices.iter().map(String::as_str).collect();
+    let old_count = count_documents(client, &old_indices, &None).await?;
+    let new_count = count_documents(client, &[&migration.new_index], &None).await?;
+
+    if old_count != new_count {
+        return Err(StepFailed(format!(
+            "Documents count mismatch: {} in {}, {} in {}",
+            old_count,
+            migration.old_indices.join(","),
+            new_count,
+            migration.new_index
+        ))
+        .into());
+    }
+
+    Ok(new_count)
+}
+
+/// Deletes the new index and forgets the migration, returns whether the index was deleted
+async fn rollback(
+    client: &Elasticsearch,
+    migration: &Migration,
+    state_path: &Path,
+) -> Result<bool> {
+    if migration.step >= Step::Swapped {
+        bail!(
+            "Alias {} has already been swapped to {}, cannot roll back",
+            migration.alias,
+            migration.new_index
+        );
+    }
+
+    let task_id = match (&migration.task_id, migration.step) {
+        (Some(task_id), _) => Some(task_id.to_owned()),
+        (None, Step::ReindexRequested) => find_reindex_task(client, &migration.new_index).await?,
+        (None, _) => None,
+    };
+    if let Some(task_id) = &task_id {
+        // Stops the reindex still writing into the new index, if any
+        client
+            .tasks()
+            .cancel(TasksCancelParts::TaskId(task_id))
+            .send()
+            .await
+            .ok();
+    }
+
+    // Even a pending migration may have created the index before being interrupted
+    let index_created = index_exists(client, &migration.new_index).await?;
+    if index_created {
+        delete_indices(client, &[migration.new_index.to_owned()]).await?;
+    }
+
+    fs::remove_file(state_path).ok();
+    Ok(index_created)
+}
+
+async fn index_exists(client: &Elasticsearch, index: &str) -> Result<bool> {
+    let response = client
+        .indices()
+        .exists(IndicesExistsParts::Index(&[index]))
+        .send()
+        .await
+        .context(format!("Request error for checking index {}", index))?;
+
+    match response.status_code().as_u16() {
+        200 => Ok(true),
+        404 => Ok(false),
+        _ => bail!("Cannot check whether index {} exists", index),
+    }
+}
+
+fn log_step(args: &Arguments, msg: String) {
+    if let Output::Default = args.output {
+        print_success(msg);
+    }
+}
+
+/// Migration states are stored in ~/.escli/migrations/<cluster>.<alias>.json
+fn get_state_path(cluster: &str, alias: &str) -> Result<PathBuf> {
+    let home_dir = dirs::home_dir().context("Unable to get home directory")?;
+    Ok(home_dir
+        .join(".escli/migrations")
+        .join(format!("{}.{}.json", cluster, alias)))
+}
+
+fn load_state(path: &Path) -> Result<Option<Migration>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let file = File::open(path).context(format!("Unable to open migration state at {:?}", path))?;
+    let migration = serde_json::from_reader(file).context(format!(
+        "Unable to deserialize migration state at {:?}",
+        path
+    ))?;
+
+    Ok(Some(migration))
+}
+
+fn save_state(path: &Path, migration: &Migration) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).context(format!(
+            "Unable to create migrations directory {:?}",
+            parent
+        ))?;
+    }
+
+    let file =
+        File::create(path).context(format!("Unable to write migration state at {:?}", path))?;
+    serde_json::to_writer_pretty(file, migration)
+        .context(format!("Unable to serialize migration state at {:?}", path))?;
+
+    Ok(())
+}
@@ -1,13 +1,56 @@
 pub mod aliases;
+pub mod api;
+pub mod cluster;
+pub mod count;
+pub mod docs;
+pub mod export;
+pub mod histogram;
+pub mod ilm;
+pub mod import;
 pub mod indices;
 pub mod mappings;
+pub mod migrate;
+pub mod nodes;
 pub mod reindex;
-pub use colored::Colorize;
+pub mod search;
+pub mod shards;
+pub mod stats;
+pub mod tasks;
+pub mod templates;
+pub mod terms;
 
 mod aliases_add;
+mod aliases_apply;
 mod aliases_list;
 mod aliases_remove;
+mod aliases_swap;
 mod aliases_update;
+mod cluster_health;
+mod cluster_info;
+mod cluster_settings;
+mod cluster_settings_get;
+mod cluster_settings_reset;
+mod cluster_settings_set;
+mod cluster_stats;
+mod docs_by_query;
+mod docs_delete;
+mod docs_delete_by_query;
+mod docs_exists;
+mod docs_get;
+mod docs_index;
+mod docs_update;
+mod docs_update_by_query;
+mod ilm_explain;
+mod ilm_move_to_step;
+mod ilm_policies;
+mod ilm_policies_delete;
+mod ilm_policies_get;
+mod ilm_policies_list;
+mod ilm_policies_put;
+mod ilm_retry;
+mod ilm_start;
+mod ilm_status;
+mod ilm_stop;
 mod indices_close;
 mod indices_create;
 mod indices_delete;
@@ -16,3 +59,22 @@ mod indices_open;
 mod indices_settings;
 mod indices_settings_get;
 mod mappings_get;
+mod mappings_infer;
+mod nodes_drain;
+mod nodes_list;
+mod nodes_show;
+mod nodes_undrain;
+mod shards_explain;
+mod shards_list;
+mod shards_move;
+mod shards_retry_failed;
+mod tasks_cancel;
+mod tasks_get;
+mod tasks_list;
+mod tasks_rethrottle;
+mod tasks_wait;
+mod templates_delete;
+mod templates_get;
+mod templates_list;
+mod templates_put;
+mod templates_simulate;
@@ -0,0 +1,193 @@
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand};
+use colored::Colorize;
+use elasticsearch::Elasticsearch;
+use serde_json::{json, Map, Value};
+
+use crate::application::Application;
+
+use super::nodes_drain;
+use super::nodes_list;
+use super::nodes_show;
+use super::nodes_undrain;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    #[command(subcommand)]
+    sub_commands: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    /// Lists the nodes of the cluster
+    List(nodes_list::Arguments),
+    /// Displays the statistics of a node
+    Show(nodes_show::Arguments),
+    /// Moves all shards away from a node before a restart or decommission
+    Drain(nodes_drain::Arguments),
+    /// Allows shards to be allocated on a drained node again
+    Undrain(nodes_undrain::Arguments),
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    match &args.sub_commands {
+        Commands::List(args) => nodes_list::handle_command(args, application).await,
+        Commands::Show(args) => nodes_show::handle_command(args, application).await,
+        Commands::Drain(args) => nodes_drain::handle_command(args, application).await,
+        Commands::Undrain(args) => nodes_undrain::handle_command(args, application).await,
+    }
+}
+
+/// Colors a metric in yellow above the warning threshold and in red above the critical one
+pub fn colorize_metric(display: &str, value: f64, warning: f64, critical: f64) -> String {
+    match value {
+        value if value >= critical => display.red().bold().to_string(),
+        value if value >= warning => display.yellow().to_string(),
+        _ => display.to_string(),
+    }
+}
+
+/// Setting holding the comma separated names of the nodes shards are moved away from
+const EXCLUDE_SETTING: &str = "cluster.routing.allocation.exclude._name";
+
+/// Adds or removes a node from the allocation exclusion list, keeping the other excluded nodes
+///
+/// A node is added to the transient list when it is defined there, since it takes precedence
+/// over the persistent one, and is removed from both lists. Only nodes of the cluster can be
+/// excluded, while a node that already left it can still be removed. Returns the excluded
+/// nodes in effect after the update.
+pub async fn update_excluded_nodes(
+    client: &Elasticsearch,
+    node: &str,
+    exclude: bool,
+) -> Result<Vec<String>> {
+    if exclude {
+        check_node_name(client, node).await?;
+    }
+
+    let response = client
+        .cluster()
+        .get_settings()
+        .flat_settings(true)
+        .send()
+        .await
+        .context("Request error for getting cluster settings")?;
+
+    if !response.status_code().is_success() {
+        bail!(
+            "Cannot get cluster settings, status {}",
+            response.status_code()
+        );
+    }
+
+    let settings: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for cluster settings")?;
+
+    let target = match settings["transient"][EXCLUDE_SETTING].is_string() {
+        true => "transient",
+        false => "persistent",
+    };
+
+    let mut body = Map::new();
+    let mut transient_nodes = vec![];
+    let mut persistent_nodes = vec![];
+    for (scope, nodes) in [
+        ("transient", &mut transient_nodes),
+        ("persistent", &mut persistent_nodes),
+    ] {
+        let current = excluded_nodes(&settings[scope]);
+        // Draining only adds the node to one list, the other one is kept as is
+        if exclude && scope != target {
+            *nodes = current;
+            continue;
+        }
+
+        *nodes = current
+            .iter()
+            .filter(|name| *name != node)
+            .cloned()
+            .collect();
+        if exclude && scope == target {
+            nodes.push(node.to_string());
+        }
+
+        if *nodes != current {
+            // An empty list is reset rather than stored as an empty string
+            let value = match nodes.is_empty() {
+                true => Value::Null,
+                false => Value::String(nodes.join(",")),
+            };
+            body.insert(scope.to_string(), json!({ EXCLUDE_SETTING: value }));
+        }
+    }
+
+    if !body.is_empty() {
+        let response = client
+            .cluster()
+            .put_settings()
+            .body(Value::Object(body))
+            .send()
+            .await
+            .context("Request error for updating cluster settings")?;
+
+        if !response.status_code().is_success() {
+            bail!(
+                "Cannot update {}, status {}",
+                EXCLUDE_SETTING,
+                response.status_code()
+            );
+        }
+    }
+
+    // A non empty transient list hides the persistent one
+    match transient_nodes.is_empty() {
+        true => Ok(persistent_nodes),
+        false => Ok(transient_nodes),
+    }
+}
+
+fn excluded_nodes(settings: &Value) -> Vec<String> {
+    settings[EXCLUDE_SETTING]
+        .as_str()
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Fails when no node of the cluster has this name, the exclusion setting only matches names
+async fn check_node_name(client: &Elasticsearch, node: &str) -> Result<()> {
+    let response = client
+        .cat()
+        .nodes()
+        .h(&["name"])
+        .format("json")
+        .send()
+        .await
+        .context("Request error for getting nodes list")?
+        .error_for_status_code()
+        .context("Cannot get nodes list")?;
+
+    let nodes: Vec<Value> = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for nodes list")?;
+
+    if !nodes.iter().any(|row| row["name"] == node) {
+        let names: Vec<&str> = nodes
+            .iter()
+            .filter_map(|row| row["name"].as_str())
+            .collect();
+        bail!(
+            "Node {} not found, nodes of the cluster: {}",
+            node,
+            names.join(", ")
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::cat::{CatAllocationParts, CatRecoveryParts};
+use elasticsearch::Elasticsearch;
+use indicatif::{ProgressBar, ProgressStyle};
+use serde_json::{json, Value};
+
+use crate::application::Application;
+use crate::utils::output::{output_json, output_yaml, print_success, Output};
+
+use super::nodes::update_excluded_nodes;
+use super::reindex::PollArguments;
+
+/// Excludes the node from shard allocation, other excluded nodes are preserved
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the node
+    node: String,
+    /// Waits until the node holds no shard
+    #[arg(short, long, default_value_t = false)]
+    wait: bool,
+    #[command(flatten)]
+    poll: PollArguments,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let excluded_nodes = update_excluded_nodes(&client, &args.node, true).await?;
+
+    if let Output::Default = args.output {
+        print_success(format!(
+            "Node {} is draining, excluded nodes: {}",
+            args.node.bold(),
+            excluded_nodes.join(", ")
+        ));
+    }
+
+    if args.wait {
+        wait_for_empty_node(&client, &args.node, args.poll.interval()).await?;
+
+        if let Output::Default = args.output {
+            print_success(format!("Node {} holds no shard", args.node.bold()));
+        }
+    }
+
+    let summary = json!({
+        "node": args.node,
+        "excluded_nodes": excluded_nodes,
+        "drained": args.wait
+    });
+
+    match args.output {
+        Output::Default => {}
+        Output::Json => output_json(&summary, args.pretty)?,
+        Output::Yaml => output_yaml(&summary)?,
+    }
+
+    Ok(())
+}
+
+async fn wait_for_empty_node(
+    client: &Elasticsearch,
+    node: &str,
+    poll_interval: Duration,
+) -> Result<()> {
+    let pb = ProgressBar::new_spinner();
+    pb.enable_steady_tick(Duration::from_millis(100));
+    pb.set_style(
+        ProgressStyle::with_template("{spinner:.blue} {prefix} [{elapsed_precise}] {msg}").unwrap(),
+    );
+    pb.set_prefix(format!("Drain {}", node));
+
+    loop {
+        let shards = count_node_shards(client, node).await?;
+        if shards == 0 {
+            pb.finish_and_clear();
+            return Ok(());
+        }
+
+        let recoveries = count_active_recoveries(client, node).await?;
+        pb.set_message(format!("{} shards left, {} relocating", shards, recoveries));
+
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+async fn count_node_shards(client: &Elasticsearch, node: &str) -> Result<u64> {
+    let response = client
+        .cat()
+        .allocation(CatAllocationParts::NodeId(&[node]))
+        .format("json")
+        .h(&["shards", "node"])
+        .send()
+        .await
+        .context(format!("Request error for getting shards of node {}", node))?
+        .error_for_status_code()
+        .context(format!("Cannot get shards of node {}", node))?;
+
+    let allocation: Vec<Value> = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for allocation")?;
+
+    Ok(allocation
+        .iter()
+        .filter(|row| row["node"] == node)
+        .filter_map(|row| row["shards"].as_str()?.parse::<u64>().ok())
+        .sum())
+}
+
+/// Recoveries moving shards away from the node
+async fn count_active_recoveries(client: &Elasticsearch, node: &str) -> Result<usize> {
+    let response = client
+        .cat()
+        .recovery(CatRecoveryParts::None)
+        .active_only(true)
+        .format("json")
+        .h(&["source_node"])
+        .send()
+        .await
+        .context("Request error for getting active recoveries")?
+        .error_for_status_code()
+        .context("Cannot get active recoveries")?;
+
+    let recoveries: Vec<Value> = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for recoveries")?;
+
+    Ok(recoveries
+        .iter()
+        .filter(|recovery| recovery["source_node"] == node)
+        .count())
+}
@@ -0,0 +1,178 @@
+use anyhow::{Context, Result};
+use clap::{Parser, ValueEnum};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use tabled::{Table, Tabled};
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{output_json, output_yaml, print_table, Output};
+
+use super::nodes::colorize_metric;
+
+#[derive(Debug, Deserialize, Serialize, Tabled)]
+pub struct Node {
+    #[tabled(display_with("Self::display_name", self))]
+    name: String,
+    #[serde(rename = "node.role")]
+    roles: String,
+    #[tabled(rename = "master", display_with = "display_master")]
+    master: String,
+    version: String,
+    #[serde(rename = "heap.percent")]
+    #[tabled(rename = "heap %", display_with = "display_heap")]
+    heap_percent: Option<String>,
+    #[serde(rename = "ram.percent")]
+    #[tabled(rename = "ram %", display_with = "display_ram")]
+    ram_percent: Option<String>,
+    #[tabled(rename = "cpu %", display_with = "display_cpu")]
+    cpu: Option<String>,
+    #[serde(rename = "load_1m")]
+    #[tabled(rename = "load 1m", display_with = "display_option")]
+    load: Option<String>,
+    #[serde(rename = "disk.used")]
+    #[tabled(rename = "disk used", display_with = "display_option")]
+    disk_used: Option<String>,
+    #[serde(rename = "disk.avail")]
+    #[tabled(rename = "disk avail", display_with = "display_option")]
+    disk_avail: Option<String>,
+    #[serde(rename = "disk.used_percent")]
+    #[tabled(rename = "disk %", display_with = "display_disk")]
+    disk_percent: Option<String>,
+    ip: String,
+}
+
+impl Node {
+    fn display_name(&self) -> String {
+        match self.master.as_str() {
+            "*" => self.name.bold().to_string(),
+            _ => self.name.to_owned(),
+        }
+    }
+}
+
+fn display_master(master: &str) -> String {
+    match master {
+        "*" => "*".green().bold().to_string(),
+        _ => "".to_string(),
+    }
+}
+
+fn display_option(o: &Option<String>) -> String {
+    match o {
+        Some(s) => s.to_owned(),
+        None => "".into(),
+    }
+}
+
+fn display_metric(o: &Option<String>, warning: f64, critical: f64) -> String {
+    match o {
+        Some(s) => colorize_metric(s, s.parse().unwrap_or(0.0), warning, critical),
+        None => "".into(),
+    }
+}
+
+fn display_heap(o: &Option<String>) -> String {
+    display_metric(o, 75.0, 90.0)
+}
+
+fn display_ram(o: &Option<String>) -> String {
+    display_metric(o, 90.0, 97.0)
+}
+
+fn display_cpu(o: &Option<String>) -> String {
+    display_metric(o, 80.0, 95.0)
+}
+
+/// Thresholds of the default low and high disk watermarks
+fn display_disk(o: &Option<String>) -> String {
+    display_metric(o, 85.0, 90.0)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum SortBy {
+    Name,
+    Heap,
+    Ram,
+    Cpu,
+    Load,
+    Disk,
+}
+
+impl SortBy {
+    /// Metrics are sorted from the busiest node
+    fn as_param(&self) -> &'static str {
+        match self {
+            SortBy::Name => "name",
+            SortBy::Heap => "heap.percent:desc",
+            SortBy::Ram => "ram.percent:desc",
+            SortBy::Cpu => "cpu:desc",
+            SortBy::Load => "load_1m:desc",
+            SortBy::Disk => "disk.used_percent:desc",
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Column used to sort the nodes
+    #[arg(short, long, value_enum, default_value_t = SortBy::Name)]
+    sort: SortBy,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = client
+        .cat()
+        .nodes()
+        .h(&[
+            "name",
+            "node.role",
+            "master",
+            "version",
+            "heap.percent",
+            "ram.percent",
+            "cpu",
+            "load_1m",
+            "disk.used",
+            "disk.avail",
+            "disk.used_percent",
+            "ip",
+        ])
+        .s(&[args.sort.as_param()])
+        .format("json")
+        .send()
+        .await
+        .context("Request error for getting nodes list")?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            "Cannot get nodes list".to_string(),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let nodes: Vec<Node> = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for nodes list")?;
+
+    match args.output {
+        Output::Default => {
+            print_table(Table::new(nodes), "Nodes");
+        }
+        Output::Json => output_json(&nodes, args.pretty)?,
+        Output::Yaml => output_yaml(&nodes)?,
+    }
+
+    Ok(())
+}
@@ -0,0 +1,240 @@
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::nodes::NodesStatsParts;
+use serde_json::Value;
+use tabled::builder::Builder;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{
+    display, format_bytes, format_percent, objects, output_json, output_yaml, print_table,
+    titled_rows, Output,
+};
+
+use super::nodes::colorize_metric;
+
+/// Thread pools always displayed, others only when they have rejections
+const MAIN_THREAD_POOLS: [&str; 6] = ["search", "write", "get", "management", "refresh", "flush"];
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name or id of the node
+    node: String,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = client
+        .nodes()
+        .stats(NodesStatsParts::NodeIdMetric(
+            &[&args.node],
+            &["jvm", "os", "fs", "thread_pool", "indices", "breaker"],
+        ))
+        .send()
+        .await
+        .context(format!(
+            "Request error for getting stats of node {}",
+            args.node
+        ))?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            format!("Cannot get stats of node {}", args.node.bold()),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for node stats")?;
+
+    let nodes = match response_body["nodes"].as_object() {
+        Some(nodes) if !nodes.is_empty() => nodes,
+        _ => bail!("Node {} not found", args.node),
+    };
+
+    match args.output {
+        Output::Default => {
+            for (id, node) in nodes {
+                output_node_stats(id, node);
+            }
+        }
+        Output::Json => output_json(&response_body, args.pretty)?,
+        Output::Yaml => output_yaml(&response_body)?,
+    }
+
+    Ok(())
+}
+
+fn output_node_stats(id: &str, node: &Value) {
+    let roles = node["roles"]
+        .as_array()
+        .map(|roles| {
+            roles
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<&str>>()
+                .join(", ")
+        })
+        .unwrap_or_default();
+
+    let heap = &node["jvm"]["mem"];
+    let heap_used = heap["heap_used_in_bytes"].as_u64().unwrap_or(0);
+    let heap_max = heap["heap_max_in_bytes"].as_u64().unwrap_or(0);
+    let heap_percent = heap["heap_used_percent"].as_f64().unwrap_or(0.0);
+    let disk_total = node["fs"]["total"]["total_in_bytes"].as_u64().unwrap_or(0);
+    let disk_available = node["fs"]["total"]["available_in_bytes"]
+        .as_u64()
+        .unwrap_or(0);
+    let disk_used = disk_total.saturating_sub(disk_available);
+    let cpu = node["os"]["cpu"]["percent"].as_f64().unwrap_or(0.0);
+    let fielddata = &node["indices"]["fielddata"];
+    let evictions = fielddata["evictions"].as_u64().unwrap_or(0);
+
+    let rows = [
+        ("Id", id.to_string()),
+        ("Host", display(&node["host"])),
+        ("IP", display(&node["ip"])),
+        ("Roles", roles),
+        (
+            "JVM heap",
+            colorize_metric(
+                &format!(
+                    "{} / {} ({}%)",
+                    format_bytes(heap_used),
+                    format_bytes(heap_max),
+                    heap_percent
+                ),
+                heap_percent,
+                75.0,
+                90.0,
+            ),
+        ),
+        (
+            "CPU",
+            colorize_metric(&format!("{}%", cpu), cpu, 80.0, 95.0),
+        ),
+        (
+            "Disk",
+            colorize_metric(
+                &format!(
+                    "{} / {} ({})",
+                    format_bytes(disk_used),
+                    format_bytes(disk_total),
+                    format_percent(disk_used, disk_total)
+                ),
+                disk_used as f64 * 100.0 / disk_total.max(1) as f64,
+                85.0,
+                90.0,
+            ),
+        ),
+        (
+            "Fielddata",
+            format!(
+                "{} ({} evictions)",
+                format_bytes(fielddata["memory_size_in_bytes"].as_u64().unwrap_or(0)),
+                colorize_metric(&evictions.to_string(), evictions as f64, 1.0, f64::MAX)
+            ),
+        ),
+    ];
+
+    let mut builder = Builder::default();
+    for (name, value) in rows {
+        builder.push_record([name.bold().to_string(), value]);
+    }
+
+    let table = titled_rows(builder.build(), &format!("Node {}", display(&node["name"])));
+    println!("{table}");
+
+    output_garbage_collectors(node);
+    output_thread_pools(node);
+    output_breakers(node);
+}
+
+fn output_garbage_collectors(node: &Value) {
+    let mut builder = Builder::default();
+    builder.set_header(["collector", "count", "time"]);
+
+    for (name, collector) in objects(&node["jvm"]["gc"]["collectors"]) {
+        builder.push_record([
+            name.to_owned(),
+            display(&collector["collection_count"]),
+            format!(
+                "{:.1}s",
+                collector["collection_time_in_millis"]
+                    .as_f64()
+                    .unwrap_or(0.0)
+                    / 1000.0
+            ),
+        ]);
+    }
+
+    print_table(builder.build(), "Garbage collection");
+}
+
+fn output_thread_pools(node: &Value) {
+    let mut builder = Builder::default();
+    builder.set_header([
+        "thread pool",
+        "threads",
+        "active",
+        "queue",
+        "rejected",
+        "completed",
+    ]);
+
+    for (name, pool) in objects(&node["thread_pool"]) {
+        let rejected = pool["rejected"].as_u64().unwrap_or(0);
+        if rejected == 0 && !MAIN_THREAD_POOLS.contains(&name.as_str()) {
+            continue;
+        }
+
+        builder.push_record([
+            name.to_owned(),
+            display(&pool["threads"]),
+            display(&pool["active"]),
+            display(&pool["queue"]),
+            colorize_metric(&rejected.to_string(), rejected as f64, f64::MAX, 1.0),
+            display(&pool["completed"]),
+        ]);
+    }
+
+    print_table(builder.build(), "Thread pools");
+}
+
+fn output_breakers(node: &Value) {
+    let mut builder = Builder::default();
+    builder.set_header(["breaker", "estimated", "limit", "usage", "tripped"]);
+
+    for (name, breaker) in objects(&node["breakers"]) {
+        let estimated = breaker["estimated_size_in_bytes"].as_u64().unwrap_or(0);
+        let limit = breaker["limit_size_in_bytes"].as_u64().unwrap_or(0);
+        let tripped = breaker["tripped"].as_u64().unwrap_or(0);
+
+        builder.push_record([
+            name.to_owned(),
+            format_bytes(estimated),
+            format_bytes(limit),
+            colorize_metric(
+                &format_percent(estimated, limit),
+                estimated as f64 * 100.0 / limit.max(1) as f64,
+                80.0,
+                95.0,
+            ),
+            colorize_metric(&tripped.to_string(), tripped as f64, f64::MAX, 1.0),
+        ]);
+    }
+
+    print_table(builder.build(), "Circuit breakers");
+}
@@ -0,0 +1,45 @@
+use anyhow::Result;
+use clap::Parser;
+use colored::Colorize;
+use serde_json::json;
+
+use crate::application::Application;
+use crate::utils::output::{output_json, output_yaml, print_success, Output};
+
+use super::nodes::update_excluded_nodes;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the node
+    node: String,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let excluded_nodes = update_excluded_nodes(&client, &args.node, false).await?;
+    let summary = json!({ "node": args.node, "excluded_nodes": excluded_nodes });
+
+    match args.output {
+        Output::Default => {
+            let excluded_nodes = match excluded_nodes.is_empty() {
+                true => "none".to_string(),
+                false => excluded_nodes.join(", "),
+            };
+            print_success(format!(
+                "Node {} accepts shards again, excluded nodes: {}",
+                args.node.bold(),
+                excluded_nodes
+            ));
+        }
+        Output::Json => output_json(&summary, args.pretty)?,
+        Output::Yaml => output_yaml(&summary)?,
+    }
+
+    Ok(())
+}
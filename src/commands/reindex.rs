@@ -1,24 +1,81 @@
-use std::time::{Duration, SystemTime};
+use std::env;
+use std::fmt;
+use std::io::{self, Write};
+use std::time::{Duration, Instant, SystemTime};
 
 use anyhow::{bail, Context, Result};
-use clap::Parser;
+use clap::{Args, Parser, ValueEnum};
 use colored::Colorize;
-use elasticsearch::tasks::TasksGetParts;
+use elasticsearch::params::{Conflicts, Slices};
 use elasticsearch::Elasticsearch;
-use indicatif::{ProgressBar, ProgressStyle};
+use indicatif::{HumanDuration, ProgressBar, ProgressStyle};
 use serde_json::{json, Value};
+use tabled::builder::Builder;
+use tabled::settings::Style;
 
 use crate::application::Application;
 use crate::utils::handle_response::handle_error_response;
-use crate::utils::output::{output_json, print_success, Output};
+use crate::utils::input::{read_stdin_line, read_text_input};
+use crate::utils::interrupt::Interrupts;
+use crate::utils::output::{output_json, output_yaml, print_error, print_success, Output};
+
+use super::search::read_query;
+use super::tasks::{cancel_task, get_task_by_id, rethrottle_task, TaskStatus};
 
 /// Copies documents from a source to a destination
+///
+/// Detached reindex tasks are followed with `tasks wait` and rethrottled with `tasks rethrottle`.
 #[derive(Debug, Parser)]
 pub struct Arguments {
     /// Name of the source index
     source_index: String,
     /// Name of the destination index
     dest_index: String,
+    /// Query selecting the documents to reindex, in the Lucene query string syntax
+    #[arg(short, long, conflicts_with = "dsl")]
+    query: Option<String>,
+    /// Query DSL selecting the documents to reindex, inline JSON or path to a JSON file
+    #[arg(long)]
+    dsl: Option<String>,
+    /// Maximum number of documents to reindex
+    #[arg(long)]
+    max_docs: Option<i64>,
+    /// Number of slices the reindex is divided into (`auto` or a number)
+    #[arg(long, value_parser = parse_slices)]
+    slices: Option<Slices>,
+    /// Throttles the reindex in sub-requests per second (`unlimited` or a number)
+    #[arg(long, value_parser = parse_requests_per_second)]
+    requests_per_second: Option<i64>,
+    /// Path to a painless script file applied to each document
+    #[arg(long)]
+    script: Option<String>,
+    /// Operation type used to write documents in the destination index
+    #[arg(long, value_enum)]
+    op_type: Option<OpType>,
+    /// Behaviour when version conflicts occur
+    #[arg(long, value_enum)]
+    conflicts: Option<ConflictsMode>,
+    /// Ingest pipeline applied to the reindexed documents
+    #[arg(long)]
+    pipeline: Option<String>,
+    /// Source fields to copy, comma separated (all fields by default)
+    #[arg(long, value_delimiter = ',')]
+    source_includes: Vec<String>,
+    /// Remote cluster to reindex from (e.g. https://otherhost:9200)
+    #[arg(long)]
+    remote_host: Option<String>,
+    /// Username used to connect to the remote cluster, the password is read from
+    /// the ESCLI_REMOTE_PASSWORD environment variable or prompted
+    #[arg(long, requires = "remote_host")]
+    remote_username: Option<String>,
+    /// Prints the reindex request without sending it
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+    /// Prints the reindex task id and exits without waiting for completion
+    #[arg(short, long, default_value_t = false)]
+    detach: bool,
+    #[command(flatten)]
+    poll: PollArguments,
     /// Output format
     #[arg(short, long, value_enum, default_value_t = Output::Default)]
     output: Output,
@@ -27,85 +84,454 @@ pub struct Arguments {
     pretty: bool,
 }
 
+/// Environment variable holding the password of the remote cluster to reindex from
+const REMOTE_PASSWORD_ENV: &str = "ESCLI_REMOTE_PASSWORD";
+
+/// Polling interval shared by the commands waiting for a task or a cluster change
+#[derive(Debug, Args)]
+pub struct PollArguments {
+    /// Interval in seconds between two status checks
+    #[arg(long, default_value_t = 2, value_parser = clap::value_parser!(u64).range(1..))]
+    poll_interval: u64,
+}
+
+impl PollArguments {
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.poll_interval)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum OpType {
+    /// Overwrites existing documents
+    Index,
+    /// Only creates missing documents
+    Create,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum ConflictsMode {
+    /// Aborts on the first version conflict
+    Abort,
+    /// Counts version conflicts and continues
+    Proceed,
+}
+
+impl ConflictsMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConflictsMode::Abort => "abort",
+            ConflictsMode::Proceed => "proceed",
+        }
+    }
+
+    pub fn to_param(self) -> Conflicts {
+        match self {
+            ConflictsMode::Abort => Conflicts::Abort,
+            ConflictsMode::Proceed => Conflicts::Proceed,
+        }
+    }
+}
+
+/// Parses a number of slices, `auto` lets Elasticsearch choose it
+pub fn parse_slices(value: &str) -> Result<Slices, String> {
+    match value {
+        "auto" => Ok(Slices::Auto),
+        _ => match value.parse::<i32>() {
+            Ok(count) if count > 0 => Ok(Slices::Count(count)),
+            _ => Err("expected `auto` or a positive number".to_string()),
+        },
+    }
+}
+
+/// Parses a throttling in sub-requests per second, `unlimited` disables it
+pub fn parse_requests_per_second(value: &str) -> Result<i64, String> {
+    match value {
+        "unlimited" | "-1" => Ok(-1),
+        _ => match value.parse::<i64>() {
+            Ok(requests_per_second) if requests_per_second > 0 => Ok(requests_per_second),
+            _ => Err("expected `unlimited` or a positive number".to_string()),
+        },
+    }
+}
+
 pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let (source_index, dest_index) = (&args.source_index, &args.dest_index);
+
+    let body = build_body(args, source_index, dest_index)?;
+
+    if args.dry_run {
+        return output_dry_run(args, &body);
+    }
+
     let client = application.get_http_client()?;
 
-    // TODO: Run reindex asynchronously and poll reindex status
-    let response = client
-        .reindex()
-        .wait_for_completion(false)
-        .body(json!({
-            "source": {
-                "index": args.source_index
-            },
-            "dest": {
-                "index": args.dest_index
-            }
-        }))
-        .send()
-        .await
-        .context(format!(
-            "Request error for reindex from {} to {}",
-            args.source_index, args.dest_index
-        ))?;
+    let mut request = client.reindex().wait_for_completion(false);
+    if let Some(slices) = &args.slices {
+        request = request.slices(slices.clone());
+    }
+    if let Some(requests_per_second) = args.requests_per_second {
+        request = request.requests_per_second(requests_per_second);
+    }
+
+    let response = request.body(body).send().await.context(format!(
+        "Request error for reindex from {} to {}",
+        source_index, dest_index
+    ))?;
 
     if !response.status_code().is_success() {
-        handle_error_response(
+        return handle_error_response(
             &args.output,
             response,
             format!(
                 "{} cannot be reindexed to {}!",
-                args.source_index.bold(),
-                args.dest_index.bold()
+                source_index.bold(),
+                dest_index.bold()
             ),
             args.pretty,
         )
-        .await?
-    } else {
-        let response_body: Value = response.json().await?;
+        .await;
+    }
 
-        let task_id = match response_body.get("task") {
-            Some(task) => task.to_string().replace("\"", ""),
-            None => bail!("Missing task in reindex response"),
-        };
+    let response_body: Value = response.json().await?;
 
-        let start = SystemTime::now();
-        let task = poll_task(&task_id, &client).await?;
-        let reindex_duration = start.elapsed()?;
+    let task_id = match response_body.get("task").and_then(Value::as_str) {
+        Some(task) => task.to_string(),
+        None => bail!("Missing task in reindex response"),
+    };
 
+    if args.detach {
         match args.output {
-            Output::Default => print_success(format!(
-                "Reindex finished successfully! ({}s)",
-                reindex_duration.as_secs()
-            )),
-            Output::Json => output_json(&task, args.pretty)?,
+            Output::Default => {
+                print_success(format!("Reindex started with task id {}", task_id.bold()));
+                println!(
+                    "Run `escli {} tasks wait {}` to follow its progress",
+                    application.cluster_name(),
+                    task_id
+                );
+            }
+            Output::Json => output_json(&response_body, args.pretty)?,
+            Output::Yaml => output_yaml(&response_body)?,
         }
+
+        return Ok(());
+    }
+
+    wait_for_task(
+        &task_id,
+        &client,
+        "Reindex",
+        args.poll.interval(),
+        &args.output,
+        args.pretty,
+    )
+    .await
+}
+
+fn build_body(args: &Arguments, source_index: &str, dest_index: &str) -> Result<Value> {
+    let mut source = json!({ "index": source_index });
+    if let Some(query) = read_query(&args.query, &args.dsl)? {
+        source["query"] = query;
+    }
+    if !args.source_includes.is_empty() {
+        source["_source"] = json!(args.source_includes);
+    }
+    if let Some(remote_host) = &args.remote_host {
+        source["remote"] = json!({ "host": remote_host });
+        if let Some(username) = &args.remote_username {
+            source["remote"]["username"] = json!(username);
+            source["remote"]["password"] = json!(read_remote_password(args.dry_run)?);
+        }
+    }
+
+    let mut dest = json!({ "index": dest_index });
+    if let Some(OpType::Create) = args.op_type {
+        dest["op_type"] = json!("create");
+    }
+    if let Some(pipeline) = &args.pipeline {
+        dest["pipeline"] = json!(pipeline);
+    }
+
+    let mut body = json!({ "source": source, "dest": dest });
+    if let Some(max_docs) = args.max_docs {
+        body["max_docs"] = json!(max_docs);
+    }
+    if let Some(conflicts) = args.conflicts {
+        body["conflicts"] = json!(conflicts.as_str());
+    }
+    if let Some(script_path) = &args.script {
+        body["script"] = json!({
+            "source": read_text_input(script_path, "script")?,
+            "lang": "painless"
+        });
+    }
+
+    Ok(body)
+}
+
+/// Reads the remote cluster password, kept out of the command line so it does not leak in
+/// the shell history or the process list
+fn read_remote_password(dry_run: bool) -> Result<String> {
+    if dry_run {
+        return Ok("******".to_string());
+    }
+
+    match env::var(REMOTE_PASSWORD_ENV) {
+        Ok(password) => Ok(password),
+        Err(_) => rpassword::prompt_password("Remote cluster password: ")
+            .context("Cannot read the remote cluster password"),
+    }
+}
+
+/// Prints the request that would be sent, with the remote password masked
+fn output_dry_run(args: &Arguments, body: &Value) -> Result<()> {
+    let mut params = vec!["wait_for_completion=false".to_string()];
+    match &args.slices {
+        Some(Slices::Auto) => params.push("slices=auto".to_string()),
+        Some(Slices::Count(count)) => params.push(format!("slices={}", count)),
+        None => {}
+    }
+    if let Some(requests_per_second) = args.requests_per_second {
+        params.push(format!("requests_per_second={}", requests_per_second));
+    }
+    let path = format!("/_reindex?{}", params.join("&"));
+
+    let mut body = body.clone();
+    if body["source"]["remote"].get("password").is_some() {
+        body["source"]["remote"]["password"] = json!("******");
+    }
+
+    let request = json!({ "method": "POST", "path": path, "body": body });
+
+    match args.output {
+        Output::Default => {
+            println!("{} {}", "POST".bold(), path);
+            output_json(&body, true)?;
+        }
+        Output::Json => output_json(&request, args.pretty)?,
+        Output::Yaml => output_yaml(&request)?,
     }
 
     Ok(())
 }
 
-async fn poll_task(task_id: &str, client: &Elasticsearch) -> Result<Value> {
-    let pb = ProgressBar::new_spinner();
-    pb.enable_steady_tick(Duration::from_millis(100));
-    pb.set_style(
-        ProgressStyle::with_template("{spinner:.blue} {msg}")
-            .unwrap()
-            // For more spinners check out the cli-spinners project:
-            // https://github.com/sindresorhus/cli-spinners/blob/master/spinners.json
-            .tick_strings(&[
-                "🕛 ", "🕚 ", "🕙 ", "🕘 ", "🕗 ", "🕖 ", "🕕 ", "🕔 ", "🕓 ", "🕒 ", "🕑 ", "🕐 ",
-            ]),
-    );
-    pb.set_message("Reindexing...");
-
-    let mut interval = tokio::time::interval(Duration::from_secs(15));
+/// Polls a task until its completion then prints its outcome, fails if the task failed
+pub async fn wait_for_task(
+    task_id: &str,
+    client: &Elasticsearch,
+    label: &str,
+    poll_interval: Duration,
+    output: &Output,
+    pretty: bool,
+) -> Result<()> {
+    let start = SystemTime::now();
+    let task = poll_task(task_id, client, label, poll_interval).await?;
+
+    // The task running time also covers the time before re-attaching to it
+    let duration = match task["task"]["running_time_in_nanos"].as_u64() {
+        Some(nanos) => Duration::from_nanos(nanos),
+        None => start.elapsed()?,
+    };
+
+    match output {
+        Output::Default => {
+            let summary = task
+                .get("response")
+                .filter(|response| response.get("total").is_some())
+                .and_then(|response| serde_json::from_value::<TaskStatus>(response.clone()).ok());
+
+            if check_task_outcome(&task, label).is_ok() {
+                print_success(format!(
+                    "{} finished successfully! ({}s)",
+                    label,
+                    duration.as_secs()
+                ));
+            }
+
+            if let Some(summary) = summary {
+                output_task_summary(&summary);
+            }
+        }
+        Output::Json => output_json(&task, pretty)?,
+        Output::Yaml => output_yaml(&task)?,
+    }
+
+    check_task_outcome(&task, label)
+}
+
+/// Fails when a completed task reports an error, was cancelled or has documents that could not
+/// be written
+pub fn check_task_outcome(task: &Value, label: &str) -> Result<()> {
+    if let Some(error) = task.get("error") {
+        let reason = match (
+            error["reason"].as_str(),
+            error["caused_by"]["reason"].as_str(),
+        ) {
+            (Some(reason), Some(cause)) => format!("{} ({})", reason, cause),
+            (Some(reason), None) => reason.to_string(),
+            _ => error.to_string(),
+        };
+        bail!("{} failed: {}", label, reason);
+    }
+
+    // Cancelled tasks complete without error, only their response tells why they stopped
+    if let Some(canceled) = task["response"].get("canceled") {
+        bail!(
+            "{} was cancelled: {}",
+            label,
+            canceled.as_str().unwrap_or("unknown reason")
+        );
+    }
+
+    if let Some(failures) = task["response"]["failures"].as_array() {
+        if let Some(failure) = failures.first() {
+            bail!(
+                "{} finished with {} failures, first one: {}",
+                label,
+                failures.len(),
+                failure["cause"]["reason"]
+                    .as_str()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| failure.to_string())
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn output_task_summary(summary: &TaskStatus) {
+    let mut builder = Builder::default();
+    builder
+        .set_header([
+            "Total",
+            "Created",
+            "Updated",
+            "Deleted",
+            "Noops",
+            "Version conflicts",
+            "Failures",
+        ])
+        .push_record([
+            summary.total.to_string(),
+            summary.created.to_string(),
+            summary.updated.to_string(),
+            summary.deleted.to_string(),
+            summary.noops.to_string(),
+            summary.version_conflicts.to_string(),
+            summary.failures.len().to_string(),
+        ]);
+
+    let mut table = builder.build();
+    table.with(Style::modern());
+    println!("{table}");
+}
+
+/// Returned by [`poll_task`] when the user stops waiting for a task that keeps running
+#[derive(Debug)]
+pub struct TaskLeftRunning {
+    pub task_id: String,
+}
+
+impl fmt::Display for TaskLeftRunning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Task {} is still running, run `tasks wait {}` to re-attach to it",
+            self.task_id, self.task_id
+        )
+    }
+}
+
+impl std::error::Error for TaskLeftRunning {}
+
+/// What to do with a running task when the user hits Ctrl-C
+enum Interruption {
+    Cancel,
+    Leave,
+    Rethrottle(i64),
+    Resume,
+}
+
+/// Polls a task until its completion while displaying its progress
+///
+/// On Ctrl-C, asks whether the task should be cancelled, left running or rethrottled.
+pub async fn poll_task(
+    task_id: &str,
+    client: &Elasticsearch,
+    label: &str,
+    poll_interval: Duration,
+) -> Result<Value> {
+    let mut pb = new_progress_bar(label, Duration::ZERO);
+    let mut interval = tokio::time::interval(poll_interval);
+    let mut has_total = false;
+    // Documents processed before attaching to the task must not count in the throughput
+    let mut first_poll: Option<(Instant, u64)> = None;
+    // Listening during the whole polling, so no Ctrl-C is lost while a status request runs
+    let mut interrupts = Interrupts::listen();
 
     loop {
-        interval.tick().await;
+        let task = tokio::select! {
+            task = async {
+                interval.tick().await;
+                get_task_by_id(task_id, client).await
+            } => task,
+            _ = interrupts.recv() => {
+                let elapsed = pb.elapsed();
+                pb.finish_and_clear();
+
+                handle_interruption(task_id, client, &mut interrupts).await?;
+
+                pb = new_progress_bar(label, elapsed);
+                has_total = false;
+                continue;
+            }
+        };
 
-        let task = get_task_by_id(task_id, client).await?;
-        // println!("{:?}", task);
+        let task = match task {
+            Ok(task) => task,
+            Err(err) => {
+                pb.finish_and_clear();
+                return Err(err);
+            }
+        };
+
+        let status: TaskStatus =
+            serde_json::from_value(task["task"]["status"].clone()).unwrap_or_default();
+        let processed = status.processed();
+
+        if status.total > 0 {
+            if !has_total {
+                pb.set_style(progress_style(true));
+                has_total = true;
+            }
+            pb.set_length(status.total);
+            pb.set_position(processed);
+        }
+
+        let (first_instant, first_processed) =
+            *first_poll.get_or_insert((Instant::now(), processed));
+        let elapsed = first_instant.elapsed().as_secs_f64();
+        let docs_per_sec = match elapsed > 0.0 {
+            true => processed.saturating_sub(first_processed) as f64 / elapsed,
+            false => 0.0,
+        };
+        let eta = match docs_per_sec > 0.0 {
+            true => {
+                let remaining = status.total.saturating_sub(processed) as f64 / docs_per_sec;
+                format!("{}", HumanDuration(Duration::from_secs_f64(remaining)))
+            }
+            false => "-".to_string(),
+        };
+
+        pb.set_message(format!(
+            "{:.0} docs/s, ETA {}, batches: {}, version conflicts: {}, retries: {}",
+            docs_per_sec,
+            eta,
+            status.batches,
+            status.version_conflicts,
+            status.retries.bulk + status.retries.search
+        ));
 
         match task.get("completed") {
             Some(completed) if completed == true => {
@@ -121,19 +547,177 @@ async fn poll_task(task_id: &str, client: &Elasticsearch) -> Result<Value> {
     }
 }
 
-async fn get_task_by_id(task_id: &str, client: &Elasticsearch) -> Result<Value> {
-    let response = client
-        .tasks()
-        .get(TasksGetParts::TaskId(&task_id))
-        .send()
-        .await
-        .context(format!("Cannot get task response for id {}", task_id))?;
+fn new_progress_bar(label: &str, elapsed: Duration) -> ProgressBar {
+    let pb = ProgressBar::new(0).with_elapsed(elapsed);
+    pb.enable_steady_tick(Duration::from_millis(100));
+    pb.set_style(progress_style(false));
+    pb.set_prefix(label.to_string());
+    pb.set_message("waiting for the task status...");
+    pb
+}
 
-    if !response.status_code().is_success() {
-        bail!("Cannot get task for id: {}", task_id);
+/// Cancels, leaves or rethrottles the task as asked, fails when polling must stop
+async fn handle_interruption(
+    task_id: &str,
+    client: &Elasticsearch,
+    interrupts: &mut Interrupts,
+) -> Result<()> {
+    match prompt_interruption(interrupts).await {
+        Interruption::Cancel => {
+            cancel_task(task_id, client).await?;
+            bail!("Task {} cancelled", task_id);
+        }
+        Interruption::Leave => Err(TaskLeftRunning {
+            task_id: task_id.to_string(),
+        }
+        .into()),
+        Interruption::Rethrottle(requests_per_second) => {
+            match rethrottle_task(task_id, client, requests_per_second).await {
+                Ok(response) if response.status_code().is_success() => {
+                    print_success(format!("Task {} rethrottled!", task_id.bold()))
+                }
+                Ok(_) => print_error(format!("Task {} cannot be rethrottled", task_id)),
+                Err(err) => print_error(format!("{:#}", err)),
+            }
+            Ok(())
+        }
+        Interruption::Resume => Ok(()),
+    }
+}
+
+async fn prompt_interruption(interrupts: &mut Interrupts) -> Interruption {
+    let answer = prompt(
+        format!(
+            "{} [c]ancel the task, [l]eave it running or [r]ethrottle it? [l] ",
+            "Interrupted!".yellow().bold()
+        ),
+        interrupts,
+    )
+    .await;
+
+    match answer.as_deref().map(str::to_lowercase).as_deref() {
+        Some("c") | Some("cancel") => Interruption::Cancel,
+        Some("r") | Some("rethrottle") => {
+            let answer = prompt(
+                "Requests per second (`unlimited` or a number): ".to_string(),
+                interrupts,
+            )
+            .await
+            .unwrap_or_default();
+
+            match parse_requests_per_second(&answer) {
+                Ok(requests_per_second) => Interruption::Rethrottle(requests_per_second),
+                Err(err) => {
+                    print_error(format!("Invalid requests per second: {}", err));
+                    Interruption::Resume
+                }
+            }
+        }
+        _ => Interruption::Leave,
     }
+}
 
-    let response_body: Value = response.json().await?;
+/// Reads an answer on stdin, a second Ctrl-C gives no answer
+async fn prompt(question: String, interrupts: &mut Interrupts) -> Option<String> {
+    eprint!("{}", question);
+    io::stderr().flush().ok();
 
-    Ok(response_body)
+    tokio::select! {
+        answer = read_stdin_line() => answer.map(|line| line.trim().to_string()),
+        _ = interrupts.recv() => {
+            eprintln!();
+            None
+        }
+    }
+}
+
+/// Progress bar style, falls back to a spinner while the number of documents is unknown
+fn progress_style(has_total: bool) -> ProgressStyle {
+    let template = match has_total {
+        true => "{spinner:.blue} {prefix} [{elapsed_precise}] [{bar:40.cyan/blue}] {human_pos}/{human_len} docs\n  {msg}",
+        false => "{spinner:.blue} {prefix} [{elapsed_precise}] {msg}",
+    };
+
+    ProgressStyle::with_template(template)
+        .unwrap()
+        .progress_chars("=> ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_task_outcome_accepts_successful_tasks() {
+        let task = json!({
+            "completed": true,
+            "response": { "total": 2, "created": 2, "failures": [] }
+        });
+        assert!(check_task_outcome(&task, "Reindex").is_ok());
+    }
+
+    #[test]
+    fn check_task_outcome_reports_errors() {
+        let task = json!({
+            "completed": true,
+            "error": {
+                "type": "index_not_found_exception",
+                "reason": "no such index",
+                "caused_by": { "reason": "[missing]" }
+            }
+        });
+        let err = check_task_outcome(&task, "Reindex").unwrap_err();
+        assert_eq!(err.to_string(), "Reindex failed: no such index ([missing])");
+    }
+
+    #[test]
+    fn check_task_outcome_reports_cancelled_tasks() {
+        let task = json!({
+            "completed": true,
+            "response": { "total": 10, "created": 3, "failures": [], "canceled": "by user request" }
+        });
+        let err = check_task_outcome(&task, "Update by query").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Update by query was cancelled: by user request"
+        );
+    }
+
+    #[test]
+    fn check_task_outcome_reports_failures() {
+        let task = json!({
+            "completed": true,
+            "response": {
+                "failures": [
+                    { "cause": { "reason": "mapper_parsing_exception" } },
+                    { "cause": { "reason": "other" } }
+                ]
+            }
+        });
+        let err = check_task_outcome(&task, "Reindex").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Reindex finished with 2 failures, first one: mapper_parsing_exception"
+        );
+    }
+
+    #[test]
+    fn parse_slices_accepts_auto_or_a_count() {
+        assert!(matches!(parse_slices("auto"), Ok(Slices::Auto)));
+        assert!(matches!(parse_slices("4"), Ok(Slices::Count(4))));
+        assert!(parse_slices("0").is_err());
+        assert!(parse_slices("-2").is_err());
+        assert!(parse_slices("Auto").is_err());
+        assert!(parse_slices("many").is_err());
+    }
+
+    #[test]
+    fn parse_requests_per_second_accepts_unlimited_or_a_rate() {
+        assert_eq!(parse_requests_per_second("unlimited"), Ok(-1));
+        assert_eq!(parse_requests_per_second("-1"), Ok(-1));
+        assert_eq!(parse_requests_per_second("500"), Ok(500));
+        assert!(parse_requests_per_second("0").is_err());
+        assert!(parse_requests_per_second("-5").is_err());
+        assert!(parse_requests_per_second("fast").is_err());
+    }
 }
@@ -0,0 +1,375 @@
+use std::collections::BTreeSet;
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::SearchParts;
+use serde_json::{json, Value};
+use tabled::builder::Builder;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::input::read_json_input;
+use crate::utils::output::{output_json, output_yaml, print_table, Output};
+
+/// Values longer than this are truncated in the hits table
+const CELL_MAX_LENGTH: usize = 60;
+
+/// Top-level keys of a search request body, a DSL without any of them is a single query
+const SEARCH_BODY_KEYS: [&str; 16] = [
+    "query",
+    "aggs",
+    "aggregations",
+    "sort",
+    "size",
+    "from",
+    "_source",
+    "fields",
+    "knn",
+    "post_filter",
+    "highlight",
+    "track_total_hits",
+    "search_after",
+    "runtime_mappings",
+    "collapse",
+    "min_score",
+];
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Indices to search, comma separated (supports wildcards)
+    index: String,
+    /// Query in the Lucene query string syntax
+    #[arg(short, long, conflicts_with = "dsl")]
+    query: Option<String>,
+    /// Query DSL (a single query or a whole search body), inline JSON or path to a JSON file
+    #[arg(short, long)]
+    dsl: Option<String>,
+    /// Number of hits to return
+    #[arg(short, long)]
+    size: Option<i64>,
+    /// Sort order, comma separated list of field:direction pairs
+    #[arg(long, value_delimiter = ',')]
+    sort: Vec<String>,
+    /// Fields displayed in the hits table, comma separated (all source fields by default)
+    #[arg(short, long, value_delimiter = ',')]
+    fields: Vec<String>,
+    /// Source fields to return, comma separated (defaults to --fields)
+    #[arg(long, value_delimiter = ',')]
+    source_includes: Vec<String>,
+    /// Explains how the score of each hit is computed
+    #[arg(long, default_value_t = false)]
+    explain: bool,
+    /// Displays the time spent by each query component on each shard
+    #[arg(long, default_value_t = false)]
+    profile: bool,
+    /// Prints the hits one per line as NDJSON instead of the table
+    #[arg(long, default_value_t = false, conflicts_with_all = ["explain", "profile"])]
+    ndjson: bool,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let body = build_body(args)?;
+    let client = application.get_http_client()?;
+
+    let indices: Vec<&str> = args.index.split(',').collect();
+    let sort: Vec<&str> = args.sort.iter().map(String::as_str).collect();
+    let source_includes: Vec<&str> = match args.source_includes.is_empty() {
+        true => args.fields.iter().map(String::as_str).collect(),
+        false => args.source_includes.iter().map(String::as_str).collect(),
+    };
+
+    let mut request = client.search(SearchParts::Index(&indices));
+    if let Some(size) = args.size {
+        request = request.size(size);
+    }
+    if !sort.is_empty() {
+        request = request.sort(&sort);
+    }
+    if !source_includes.is_empty() {
+        request = request._source_includes(&source_includes);
+    }
+    if args.explain {
+        request = request.explain(true);
+    }
+
+    let response = request
+        .body(body)
+        .send()
+        .await
+        .context(format!("Request error for searching {}", args.index))?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            format!("Cannot search {}", args.index.bold()),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON search response")?;
+    let hits = match response_body["hits"]["hits"].as_array() {
+        Some(hits) => hits.to_owned(),
+        None => vec![],
+    };
+
+    if args.ndjson {
+        for hit in &hits {
+            output_json(hit, false)?;
+        }
+        return Ok(());
+    }
+
+    match args.output {
+        Output::Default => {
+            output_hits(&hits, &args.fields);
+            println!("{}", summary(&response_body));
+
+            if args.explain {
+                for hit in &hits {
+                    println!(
+                        "\n{} {}",
+                        "Explanation of".bold(),
+                        hit["_id"].as_str().unwrap_or_default().bold()
+                    );
+                    print_explanation(&hit["_explanation"], 0);
+                }
+            }
+
+            if args.profile {
+                print_profile(&response_body["profile"]);
+            }
+        }
+        Output::Json => output_json(&response_body, args.pretty)?,
+        Output::Yaml => output_yaml(&response_body)?,
+    }
+
+    Ok(())
+}
+
+fn build_body(args: &Arguments) -> Result<Value> {
+    let mut body = match (&args.query, &args.dsl) {
+        (Some(query), _) => json!({ "query": lucene_query(query) }),
+        (None, Some(dsl)) => match read_json_input(dsl, "query DSL")? {
+            Value::Object(dsl) if SEARCH_BODY_KEYS.iter().any(|key| dsl.contains_key(*key)) => {
+                Value::Object(dsl)
+            }
+            query => json!({ "query": query }),
+        },
+        (None, None) => json!({}),
+    };
+
+    if args.profile {
+        body["profile"] = json!(true);
+    }
+
+    Ok(body)
+}
+
+/// Query matching a Lucene query string (e.g. `status:error AND service:api`)
+pub fn lucene_query(query: &str) -> Value {
+    json!({
+        "query_string": {
+            "query": query
+        }
+    })
+}
+
+/// Query given either with `--query` in the Lucene syntax or with `--dsl` as query DSL
+pub fn read_query(query: &Option<String>, dsl: &Option<String>) -> Result<Option<Value>> {
+    match (query, dsl) {
+        (Some(query), _) => Ok(Some(lucene_query(query))),
+        (None, Some(dsl)) => read_json_input(dsl, "query DSL").map(Some),
+        (None, None) => Ok(None),
+    }
+}
+
+fn output_hits(hits: &[Value], fields: &[String]) {
+    let indices: BTreeSet<&str> = hits
+        .iter()
+        .filter_map(|hit| hit["_index"].as_str())
+        .collect();
+    let show_index = indices.len() > 1;
+
+    // Without selected fields, every top-level source field of the hits
+    let columns: Vec<String> = match fields.is_empty() {
+        false => fields.to_vec(),
+        true => {
+            let mut columns: Vec<String> = Vec::new();
+            for hit in hits {
+                if let Some(source) = hit["_source"].as_object() {
+                    for key in source.keys() {
+                        if !columns.contains(key) {
+                            columns.push(key.to_owned());
+                        }
+                    }
+                }
+            }
+            columns
+        }
+    };
+
+    let mut header = vec![];
+    if show_index {
+        header.push("_index".to_string());
+    }
+    header.push("_id".to_string());
+    header.push("_score".to_string());
+    header.extend(columns.iter().cloned());
+
+    let mut builder = Builder::default();
+    builder.set_header(header);
+
+    for hit in hits {
+        let mut record = vec![];
+        if show_index {
+            record.push(display_value(&hit["_index"]));
+        }
+        record.push(display_value(&hit["_id"]));
+        record.push(display_value(&hit["_score"]));
+        record.extend(
+            columns
+                .iter()
+                .map(|column| display_value(&field_value(hit, column))),
+        );
+        builder.push_record(record);
+    }
+
+    print_table(builder.build(), "Hits");
+}
+
+/// Looks up a dotted path in the source, then in the fields returned by the `fields` option
+fn field_value(hit: &Value, path: &str) -> Value {
+    let mut value = &hit["_source"];
+    for key in path.split('.') {
+        value = &value[key];
+    }
+
+    match value {
+        Value::Null => hit["fields"][path].to_owned(),
+        value => value.to_owned(),
+    }
+}
+
+fn display_value(value: &Value) -> String {
+    let value = match value {
+        Value::Null => "".to_string(),
+        Value::String(s) => s.to_owned(),
+        value => value.to_string(),
+    };
+
+    match value.chars().count() > CELL_MAX_LENGTH {
+        true => format!(
+            "{}…",
+            value.chars().take(CELL_MAX_LENGTH - 1).collect::<String>()
+        ),
+        false => value,
+    }
+}
+
+fn summary(response_body: &Value) -> String {
+    let total = &response_body["hits"]["total"];
+    let total = match total["relation"].as_str() {
+        Some("gte") => format!("≥ {}", total["value"]),
+        _ => total["value"].to_string(),
+    };
+
+    let mut summary = format!(
+        "Total hits: {}, took {} ms",
+        total.bold(),
+        response_body["took"]
+    );
+    if response_body["timed_out"].as_bool() == Some(true) {
+        summary.push_str(
+            &" (timed out, results may be incomplete)"
+                .yellow()
+                .to_string(),
+        );
+    }
+
+    summary
+}
+
+fn print_explanation(explanation: &Value, depth: usize) {
+    println!(
+        "{}{} {}",
+        "  ".repeat(depth),
+        format!("{:.4}", explanation["value"].as_f64().unwrap_or_default()).bold(),
+        explanation["description"].as_str().unwrap_or_default()
+    );
+
+    if let Some(details) = explanation["details"].as_array() {
+        for detail in details {
+            print_explanation(detail, depth + 1);
+        }
+    }
+}
+
+fn print_profile(profile: &Value) {
+    let shards = match profile["shards"].as_array() {
+        Some(shards) => shards,
+        None => return,
+    };
+
+    for shard in shards {
+        println!(
+            "\n{} {}",
+            "Profile of shard".bold(),
+            shard["id"].as_str().unwrap_or_default().bold()
+        );
+
+        if let Some(searches) = shard["searches"].as_array() {
+            for search in searches {
+                for query in search["query"].as_array().into_iter().flatten() {
+                    print_profile_node(query, "type", 1);
+                }
+                println!(
+                    "  {} {}",
+                    "rewrite".bold(),
+                    display_nanos(&search["rewrite_time"])
+                );
+                for collector in search["collector"].as_array().into_iter().flatten() {
+                    print_profile_node(collector, "name", 1);
+                }
+            }
+        }
+
+        for aggregation in shard["aggregations"].as_array().into_iter().flatten() {
+            print_profile_node(aggregation, "type", 1);
+        }
+    }
+}
+
+fn print_profile_node(node: &Value, name_key: &str, depth: usize) {
+    let description = node["description"]
+        .as_str()
+        .or_else(|| node["reason"].as_str())
+        .unwrap_or_default();
+
+    println!(
+        "{}{} {} {}",
+        "  ".repeat(depth),
+        node[name_key].as_str().unwrap_or_default().bold(),
+        display_nanos(&node["time_in_nanos"]).cyan(),
+        description
+    );
+
+    for child in node["children"].as_array().into_iter().flatten() {
+        print_profile_node(child, name_key, depth + 1);
+    }
+}
+
+fn display_nanos(nanos: &Value) -> String {
+    format!("{:.3} ms", nanos.as_f64().unwrap_or_default() / 1_000_000.0)
+}
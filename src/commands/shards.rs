@@ -0,0 +1,36 @@
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+use crate::application::Application;
+
+use super::shards_explain;
+use super::shards_list;
+use super::shards_move;
+use super::shards_retry_failed;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    #[command(subcommand)]
+    sub_commands: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    /// Lists shards with their state and allocation
+    List(shards_list::Arguments),
+    /// Explains why a shard is unassigned or where it is allocated
+    Explain(shards_explain::Arguments),
+    /// Moves a started shard from one node to another
+    Move(shards_move::Arguments),
+    /// Retries the allocation of shards that failed too many times
+    RetryFailed(shards_retry_failed::Arguments),
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    match &args.sub_commands {
+        Commands::List(args) => shards_list::handle_command(args, application).await,
+        Commands::Explain(args) => shards_explain::handle_command(args, application).await,
+        Commands::Move(args) => shards_move::handle_command(args, application).await,
+        Commands::RetryFailed(args) => shards_retry_failed::handle_command(args, application).await,
+    }
+}
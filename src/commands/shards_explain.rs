@@ -0,0 +1,271 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use serde_json::{json, Value};
+use tabled::builder::Builder;
+use tabled::settings::object::Columns;
+use tabled::settings::{Modify, Width};
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{
+    display, output_json, output_yaml, print_error, print_success, titled_rows, titled_table,
+    Output,
+};
+
+/// Without arguments, explains the first unassigned shard found by the cluster
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the index
+    #[arg(requires = "shard")]
+    index: Option<String>,
+    /// Number of the shard
+    #[arg(requires = "index")]
+    shard: Option<u32>,
+    /// Explains the primary shard instead of a replica
+    #[arg(long, default_value_t = false, requires = "index")]
+    primary: bool,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let body = match (&args.index, args.shard) {
+        (Some(index), Some(shard)) => json!({
+            "index": index,
+            "shard": shard,
+            "primary": args.primary
+        }),
+        _ => json!({}),
+    };
+
+    let client = application.get_http_client()?;
+    let response = client
+        .cluster()
+        .allocation_explain()
+        .body(body)
+        .send()
+        .await
+        .context("Request error for explaining shard allocation")?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            "Cannot explain shard allocation".to_string(),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let explanation: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for allocation explain")?;
+
+    match args.output {
+        Output::Default => {
+            output_summary(&explanation);
+            output_node_decisions(&explanation);
+            output_blocking_reason(&explanation);
+        }
+        Output::Json => output_json(&explanation, args.pretty)?,
+        Output::Yaml => output_yaml(&explanation)?,
+    }
+
+    Ok(())
+}
+
+fn output_summary(explanation: &Value) {
+    let shard_type = match explanation["primary"].as_bool() {
+        Some(true) => "primary",
+        _ => "replica",
+    };
+    let state = explanation["current_state"].as_str().unwrap_or_default();
+    let unassigned_info = &explanation["unassigned_info"];
+
+    let mut rows = vec![
+        (
+            "Shard",
+            format!(
+                "[{}][{}] {}",
+                display(&explanation["index"]),
+                display(&explanation["shard"]),
+                shard_type
+            ),
+        ),
+        (
+            "State",
+            match state {
+                "started" => state.green(),
+                "unassigned" => state.red().bold(),
+                _ => state.yellow(),
+            }
+            .to_string(),
+        ),
+    ];
+
+    if let Some(node) = explanation.get("current_node") {
+        rows.push(("Current node", display(&node["name"])));
+    }
+
+    if unassigned_info.is_object() {
+        rows.push((
+            "Unassigned",
+            format!(
+                "{} since {}",
+                display(&unassigned_info["reason"]),
+                display(&unassigned_info["at"])
+            ),
+        ));
+        if let Some(details) = unassigned_info["details"].as_str() {
+            rows.push(("Details", details.to_string()));
+        }
+        if let Some(status) = unassigned_info["last_allocation_status"].as_str() {
+            rows.push(("Last allocation status", status.to_string()));
+        }
+    }
+
+    // Unassigned shards get an allocation decision, assigned ones a move and a rebalance decision
+    for (name, key) in [
+        ("Can allocate", "can_allocate"),
+        ("Can remain on node", "can_remain_on_current_node"),
+        ("Can move", "can_move_to_other_node"),
+        ("Can rebalance", "can_rebalance_to_other_node"),
+    ] {
+        if let Some(decision) = explanation[key].as_str() {
+            rows.push((name, display_decision(decision)));
+        }
+    }
+
+    for key in [
+        "allocate_explanation",
+        "move_explanation",
+        "rebalance_explanation",
+    ] {
+        if let Some(text) = explanation[key].as_str() {
+            rows.push(("Explanation", text.to_string()));
+        }
+    }
+
+    let mut builder = Builder::default();
+    for (name, value) in rows {
+        builder.push_record([name.bold().to_string(), value]);
+    }
+
+    let mut table = titled_rows(builder.build(), "Allocation explain");
+    table.with(Modify::new(Columns::single(1)).with(Width::wrap(100).keep_words()));
+
+    println!("{table}");
+}
+
+fn output_node_decisions(explanation: &Value) {
+    let decisions = match explanation["node_allocation_decisions"].as_array() {
+        Some(decisions) if !decisions.is_empty() => decisions,
+        _ => return,
+    };
+
+    let mut builder = Builder::default();
+    builder.set_header(["node", "decision", "weight", "blocking deciders"]);
+
+    for decision in decisions {
+        let blocking: Vec<String> = blocking_deciders(decision)
+            .map(|decider| {
+                format!(
+                    "{}: {}",
+                    display(&decider["decider"]).bold(),
+                    display(&decider["explanation"])
+                )
+            })
+            .collect();
+
+        // Stale primaries are decided by the shard copies found on disk
+        let blocking = match (blocking.is_empty(), decision.get("store")) {
+            (true, Some(store)) if store["found"] == false => "no valid shard copy".to_string(),
+            _ => blocking.join("\n"),
+        };
+
+        builder.push_record([
+            display(&decision["node_name"]),
+            display_decision(decision["node_decision"].as_str().unwrap_or_default()),
+            display(&decision["weight_ranking"]),
+            blocking,
+        ]);
+    }
+
+    let mut table = titled_table(builder.build(), "Node decisions");
+    table.with(Modify::new(Columns::single(3)).with(Width::wrap(100).keep_words()));
+
+    println!("{table}");
+}
+
+/// Deciders preventing the allocation on most nodes are the actual blocking reason
+fn output_blocking_reason(explanation: &Value) {
+    let decisions = match explanation["node_allocation_decisions"].as_array() {
+        Some(decisions) if !decisions.is_empty() => decisions,
+        _ => return,
+    };
+
+    let accepting_nodes = decisions
+        .iter()
+        .filter(|decision| decision["node_decision"] == "yes")
+        .count();
+    if accepting_nodes > 0 {
+        print_success(format!(
+            "{} of {} nodes can hold this shard",
+            accepting_nodes,
+            decisions.len()
+        ));
+        return;
+    }
+
+    let mut deciders: BTreeMap<String, (usize, String)> = BTreeMap::new();
+    for decision in decisions {
+        for decider in blocking_deciders(decision) {
+            let entry = deciders
+                .entry(display(&decider["decider"]))
+                .or_insert((0, display(&decider["explanation"])));
+            entry.0 += 1;
+        }
+    }
+
+    let mut deciders: Vec<(String, (usize, String))> = deciders.into_iter().collect();
+    deciders.sort_by_key(|(_, (nodes, _))| std::cmp::Reverse(*nodes));
+
+    print_error(format!(
+        "No node can hold this shard ({} nodes checked)",
+        decisions.len()
+    ));
+    for (decider, (nodes, explanation)) in deciders {
+        println!(
+            "  {} blocks {} of {} nodes: {}",
+            decider.bold(),
+            nodes,
+            decisions.len(),
+            explanation
+        );
+    }
+}
+
+fn blocking_deciders(decision: &Value) -> impl Iterator<Item = &Value> {
+    decision["deciders"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|decider| decider["decision"] != "YES")
+}
+
+fn display_decision(decision: &str) -> String {
+    match decision {
+        "yes" => decision.green(),
+        "throttled" | "throttle" | "worse_balance" => decision.yellow(),
+        _ => decision.red(),
+    }
+    .to_string()
+}
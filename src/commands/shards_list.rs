@@ -0,0 +1,155 @@
+use anyhow::{Context, Result};
+use clap::{Parser, ValueEnum};
+use colored::Colorize;
+use elasticsearch::cat::CatShardsParts;
+use serde::{Deserialize, Serialize};
+use tabled::{Table, Tabled};
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{output_json, output_yaml, print_table, Output};
+
+#[derive(Debug, Deserialize, Serialize, Tabled)]
+pub struct Shard {
+    index: String,
+    shard: String,
+    #[tabled(rename = "type", display_with = "display_prirep")]
+    prirep: String,
+    #[tabled(display_with = "display_state")]
+    state: String,
+    #[tabled(display_with = "display_option")]
+    docs: Option<String>,
+    #[tabled(display_with = "display_option")]
+    store: Option<String>,
+    #[tabled(display_with = "display_option")]
+    node: Option<String>,
+    #[serde(rename = "unassigned.reason")]
+    #[tabled(rename = "unassigned reason", display_with = "display_option")]
+    unassigned_reason: Option<String>,
+    #[serde(rename = "unassigned.for")]
+    #[tabled(rename = "unassigned for", display_with = "display_option")]
+    unassigned_for: Option<String>,
+}
+
+fn display_prirep(prirep: &str) -> String {
+    match prirep {
+        "p" => "primary".to_string(),
+        "r" => "replica".to_string(),
+        prirep => prirep.to_string(),
+    }
+}
+
+fn display_state(state: &str) -> String {
+    match state {
+        "STARTED" => state.green(),
+        "RELOCATING" | "INITIALIZING" => state.yellow(),
+        "UNASSIGNED" => state.red().bold(),
+        _ => state.normal(),
+    }
+    .to_string()
+}
+
+fn display_option(o: &Option<String>) -> String {
+    match o {
+        Some(s) => s.to_owned(),
+        None => "".into(),
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+#[value(rename_all = "UPPER")]
+enum ShardState {
+    Started,
+    Relocating,
+    Initializing,
+    Unassigned,
+}
+
+impl ShardState {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ShardState::Started => "STARTED",
+            ShardState::Relocating => "RELOCATING",
+            ShardState::Initializing => "INITIALIZING",
+            ShardState::Unassigned => "UNASSIGNED",
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Only lists the shards of these indices, comma separated (supports wildcards)
+    #[arg(short, long)]
+    index: Option<String>,
+    /// Only lists the shards in this state
+    #[arg(short, long, value_enum, ignore_case = true)]
+    state: Option<ShardState>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+
+    let indices: Vec<&str> = match &args.index {
+        Some(index) => index.split(',').collect(),
+        None => vec![],
+    };
+    let parts = match indices.is_empty() {
+        true => CatShardsParts::None,
+        false => CatShardsParts::Index(&indices),
+    };
+
+    let response = client
+        .cat()
+        .shards(parts)
+        .h(&[
+            "index",
+            "shard",
+            "prirep",
+            "state",
+            "docs",
+            "store",
+            "node",
+            "unassigned.reason",
+            "unassigned.for",
+        ])
+        .s(&["index", "shard", "prirep"])
+        .format("json")
+        .send()
+        .await
+        .context("Request error for getting shards list")?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            "Cannot get shards list".to_string(),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let mut shards: Vec<Shard> = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for shards list")?;
+
+    if let Some(state) = args.state {
+        shards.retain(|shard| shard.state == state.as_str());
+    }
+
+    match args.output {
+        Output::Default => {
+            print_table(Table::new(shards), "Shards");
+        }
+        Output::Json => output_json(&shards, args.pretty)?,
+        Output::Yaml => output_yaml(&shards)?,
+    }
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use serde_json::json;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_response;
+use crate::utils::output::Output;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the index
+    index: String,
+    /// Number of the shard
+    shard: u32,
+    /// Node currently holding the shard
+    from: String,
+    /// Node receiving the shard
+    to: String,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = client
+        .cluster()
+        .reroute()
+        .metric(&["none"])
+        .body(json!({
+            "commands": [{
+                "move": {
+                    "index": args.index,
+                    "shard": args.shard,
+                    "from_node": args.from,
+                    "to_node": args.to
+                }
+            }]
+        }))
+        .send()
+        .await
+        .context(format!(
+            "Request error for moving shard {} of {}",
+            args.shard, args.index
+        ))?;
+
+    let shard = format!("[{}][{}]", args.index, args.shard);
+
+    handle_response(
+        &args.output,
+        response,
+        format!(
+            "Shard {} is moving from {} to {}!",
+            shard.bold(),
+            args.from.bold(),
+            args.to.bold()
+        ),
+        format!("Shard {} cannot be moved", shard.bold()),
+        args.pretty,
+    )
+    .await
+}
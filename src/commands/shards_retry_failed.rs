@@ -0,0 +1,38 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_response;
+use crate::utils::output::Output;
+
+/// Shards stop being allocated after `index.allocation.max_retries` failed attempts
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = client
+        .cluster()
+        .reroute()
+        .retry_failed(true)
+        .metric(&["none"])
+        .send()
+        .await
+        .context("Request error for retrying failed shards allocation")?;
+
+    handle_response(
+        &args.output,
+        response,
+        "Allocation of failed shards retried!".to_string(),
+        "Allocation of failed shards cannot be retried".to_string(),
+        args.pretty,
+    )
+    .await
+}
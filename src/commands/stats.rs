@@ -0,0 +1,172 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::SearchParts;
+use serde_json::{json, Value};
+use tabled::builder::Builder;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{
+    format_bar, output_json, output_yaml, print_table, titled_rows, Output,
+};
+
+use super::search::lucene_query;
+
+/// Width of the bars, in characters
+const BAR_WIDTH: usize = 30;
+
+const PERCENTS: [f64; 7] = [1.0, 5.0, 25.0, 50.0, 75.0, 95.0, 99.0];
+
+/// Displays the statistics and percentiles of a numeric or date field
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Indices to aggregate, comma separated (supports wildcards)
+    index: String,
+    /// Numeric or date field to aggregate
+    field: String,
+    /// Query in the Lucene query string syntax
+    #[arg(short, long)]
+    query: Option<String>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let mut body = json!({
+        "size": 0,
+        "aggs": {
+            "stats": { "extended_stats": { "field": args.field } },
+            "percentiles": { "percentiles": { "field": args.field, "percents": PERCENTS } },
+            "missing": { "missing": { "field": args.field } }
+        }
+    });
+    if let Some(query) = &args.query {
+        body["query"] = lucene_query(query);
+    }
+
+    let client = application.get_http_client()?;
+    let indices: Vec<&str> = args.index.split(',').collect();
+    let response = client
+        .search(SearchParts::Index(&indices))
+        .body(body)
+        .send()
+        .await
+        .context(format!(
+            "Request error for aggregating {} of {}",
+            args.field, args.index
+        ))?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            format!(
+                "Cannot aggregate {} of {}",
+                args.field.bold(),
+                args.index.bold()
+            ),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for field statistics")?;
+    let aggregations = &response_body["aggregations"];
+
+    match args.output {
+        Output::Default => {
+            output_stats(args, aggregations);
+            output_percentiles(&aggregations["percentiles"]);
+        }
+        Output::Json => output_json(aggregations, args.pretty)?,
+        Output::Yaml => output_yaml(aggregations)?,
+    }
+
+    Ok(())
+}
+
+fn output_stats(args: &Arguments, aggregations: &Value) {
+    let stats = &aggregations["stats"];
+
+    let rows = [
+        ("Count", display_number(&stats["count"])),
+        (
+            "Missing",
+            display_number(&aggregations["missing"]["doc_count"]),
+        ),
+        ("Min", display_stat(stats, "min")),
+        ("Max", display_stat(stats, "max")),
+        ("Avg", display_stat(stats, "avg")),
+        ("Sum", display_stat(stats, "sum")),
+        ("Std deviation", display_stat(stats, "std_deviation")),
+    ];
+
+    let mut builder = Builder::default();
+    for (name, value) in rows {
+        builder.push_record([name.bold().to_string(), value]);
+    }
+
+    let table = titled_rows(builder.build(), &format!("Statistics of {}", args.field));
+
+    println!("{table}");
+}
+
+fn output_percentiles(percentiles: &Value) {
+    let mut values: Vec<(f64, &String, &Value)> = match percentiles["values"].as_object() {
+        Some(values) => values
+            .iter()
+            .filter_map(|(percent, value)| Some((percent.parse().ok()?, percent, value)))
+            .collect(),
+        None => return,
+    };
+    // Percents are keys of a JSON object, sorted as strings
+    values.sort_by(|(a, _, _), (b, _, _)| a.total_cmp(b));
+    let max = values
+        .iter()
+        .filter_map(|(_, _, value)| value.as_f64())
+        .fold(0.0, f64::max);
+
+    let mut builder = Builder::default();
+    builder.set_header(["percentile", "value", ""]);
+
+    for (_, percent, value) in values {
+        let display = match percentiles["values"][format!("{}_as_string", percent)].as_str() {
+            Some(date) => date.to_string(),
+            None => display_number(value),
+        };
+
+        builder.push_record([
+            format!("p{}", percent.trim_end_matches(".0")),
+            display,
+            format_bar(value.as_f64().unwrap_or(0.0), max, BAR_WIDTH)
+                .blue()
+                .to_string(),
+        ]);
+    }
+
+    print_table(builder.build(), "Percentiles");
+}
+
+/// Dates are also returned formatted, as `<stat>_as_string`
+fn display_stat(stats: &Value, name: &str) -> String {
+    match stats[format!("{}_as_string", name)].as_str() {
+        Some(value) => value.to_string(),
+        None => display_number(&stats[name]),
+    }
+}
+
+fn display_number(value: &Value) -> String {
+    match value.as_f64() {
+        Some(number) if number.fract() == 0.0 => format!("{:.0}", number),
+        Some(number) => format!("{:.2}", number),
+        None => "-".to_string(),
+    }
+}
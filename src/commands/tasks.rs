@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand};
+use elasticsearch::http::response::Response;
+use elasticsearch::tasks::{TasksCancelParts, TasksGetParts};
+use elasticsearch::{
+    DeleteByQueryRethrottleParts, Elasticsearch, ReindexRethrottleParts,
+    UpdateByQueryRethrottleParts,
+};
+use indicatif::HumanDuration;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::application::Application;
+use crate::utils::output::format_percent;
+
+use super::tasks_cancel;
+use super::tasks_get;
+use super::tasks_list;
+use super::tasks_rethrottle;
+use super::tasks_wait;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    #[command(subcommand)]
+    sub_commands: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    /// Lists the tasks running in the cluster
+    List(tasks_list::Arguments),
+    /// Displays a task and its progress
+    Get(tasks_get::Arguments),
+    /// Cancels a task or all the tasks matching some actions
+    Cancel(tasks_cancel::Arguments),
+    /// Waits for a task completion
+    Wait(tasks_wait::Arguments),
+    /// Changes the throttling of a reindex, update by query or delete by query task
+    Rethrottle(tasks_rethrottle::Arguments),
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    match &args.sub_commands {
+        Commands::List(args) => tasks_list::handle_command(args, application).await,
+        Commands::Get(args) => tasks_get::handle_command(args, application).await,
+        Commands::Cancel(args) => tasks_cancel::handle_command(args, application).await,
+        Commands::Wait(args) => tasks_wait::handle_command(args, application).await,
+        Commands::Rethrottle(args) => tasks_rethrottle::handle_command(args, application).await,
+    }
+}
+
+/// Progress counters of a reindex, update by query or delete by query task
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct TaskStatus {
+    pub total: u64,
+    pub created: u64,
+    pub updated: u64,
+    pub deleted: u64,
+    pub batches: u64,
+    pub version_conflicts: u64,
+    pub noops: u64,
+    pub retries: TaskRetries,
+    /// Only available in the response of a completed task
+    pub failures: Vec<Value>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct TaskRetries {
+    pub bulk: u64,
+    pub search: u64,
+}
+
+impl TaskStatus {
+    pub fn processed(&self) -> u64 {
+        self.created + self.updated + self.deleted + self.noops + self.version_conflicts
+    }
+}
+
+/// Formats the progress of the tasks reporting a number of documents to process
+pub fn format_progress(status: &Value) -> String {
+    let status: TaskStatus = serde_json::from_value(status.clone()).unwrap_or_default();
+
+    match status.total {
+        0 => "-".to_string(),
+        total => format!(
+            "{}/{} ({})",
+            status.processed(),
+            total,
+            format_percent(status.processed(), total)
+        ),
+    }
+}
+
+pub fn format_running_time(task: &Value) -> String {
+    match task["running_time_in_nanos"].as_u64() {
+        Some(nanos) => HumanDuration(Duration::from_nanos(nanos)).to_string(),
+        None => "-".to_string(),
+    }
+}
+
+pub async fn cancel_task(task_id: &str, client: &Elasticsearch) -> Result<()> {
+    let response = client
+        .tasks()
+        .cancel(TasksCancelParts::TaskId(task_id))
+        .send()
+        .await
+        .context(format!("Request error for cancelling task {}", task_id))?;
+
+    if !response.status_code().is_success() {
+        bail!("Task {} cannot be cancelled", task_id);
+    }
+
+    Ok(())
+}
+
+pub async fn get_task_by_id(task_id: &str, client: &Elasticsearch) -> Result<Value> {
+    let response = client
+        .tasks()
+        .get(TasksGetParts::TaskId(task_id))
+        .send()
+        .await
+        .context(format!("Cannot get task response for id {}", task_id))?;
+
+    if !response.status_code().is_success() {
+        bail!(
+            "Cannot get task for id: {} (status code {})",
+            task_id,
+            response.status_code()
+        );
+    }
+
+    let response_body: Value = response.json().await?;
+
+    Ok(response_body)
+}
+
+/// Rethrottles a reindex, update by query or delete by query task
+pub async fn rethrottle_task(
+    task_id: &str,
+    client: &Elasticsearch,
+    requests_per_second: i64,
+) -> Result<Response> {
+    let task = get_task_by_id(task_id, client).await?;
+    let action = task["task"]["action"].as_str().unwrap_or_default();
+
+    let response = if action.ends_with("/reindex") {
+        client
+            .reindex_rethrottle(ReindexRethrottleParts::TaskId(task_id))
+            .requests_per_second(requests_per_second)
+            .send()
+            .await
+    } else if action.ends_with("/update/byquery") {
+        client
+            .update_by_query_rethrottle(UpdateByQueryRethrottleParts::TaskId(task_id))
+            .requests_per_second(requests_per_second)
+            .send()
+            .await
+    } else if action.ends_with("/delete/byquery") {
+        client
+            .delete_by_query_rethrottle(DeleteByQueryRethrottleParts::TaskId(task_id))
+            .requests_per_second(requests_per_second)
+            .send()
+            .await
+    } else {
+        bail!("Task {} ({}) cannot be rethrottled", task_id, action);
+    }
+    .context(format!("Request error for rethrottling task {}", task_id))?;
+
+    Ok(response)
+}
@@ -0,0 +1,109 @@
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::tasks::TasksCancelParts;
+use serde_json::Value;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::input::confirm;
+use crate::utils::output::{objects, output_json, output_yaml, print_error, print_success, Output};
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Id of the task to cancel
+    #[arg(required_unless_present = "actions", conflicts_with = "actions")]
+    task_id: Option<String>,
+    /// Cancels all the tasks matching these actions, comma separated (supports wildcards, e.g. *byquery)
+    #[arg(short, long)]
+    actions: Option<String>,
+    /// Cancels the tasks matching the actions without asking for confirmation
+    #[arg(short, long, default_value_t = false)]
+    yes: bool,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let actions: Vec<&str> = match &args.actions {
+        Some(actions) => actions.split(',').map(str::trim).collect(),
+        None => vec![],
+    };
+    let target = match &args.task_id {
+        Some(task_id) => format!("task {}", task_id),
+        None => format!("tasks matching {}", actions.join(", ")),
+    };
+
+    if args.actions.is_some() && !args.yes && !confirm(&format!("Cancel all the {}?", target))? {
+        bail!("Tasks not cancelled");
+    }
+
+    let client = application.get_http_client()?;
+    let tasks = client.tasks();
+    let response = match &args.task_id {
+        Some(task_id) => tasks.cancel(TasksCancelParts::TaskId(task_id)).send(),
+        None => tasks
+            .cancel(TasksCancelParts::None)
+            .actions(&actions)
+            .send(),
+    }
+    .await
+    .context(format!("Request error for cancelling {}", target))?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            format!("Cannot cancel {}", target.bold()),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for task cancellation")?;
+
+    match args.output {
+        Output::Default => output_cancelled_tasks(&response_body, &target),
+        Output::Json => output_json(&response_body, args.pretty)?,
+        Output::Yaml => output_yaml(&response_body)?,
+    }
+
+    Ok(())
+}
+
+fn output_cancelled_tasks(response_body: &Value, target: &str) {
+    let task_ids: Vec<&String> = objects(&response_body["nodes"])
+        .flat_map(|(_, node)| objects(&node["tasks"]))
+        .map(|(task_id, _)| task_id)
+        .collect();
+
+    match task_ids.is_empty() {
+        true => print_error(format!("No running {}", target)),
+        false => {
+            print_success(format!("Cancelled {} tasks!", task_ids.len()));
+            for task_id in task_ids {
+                println!("  {}", task_id);
+            }
+        }
+    }
+
+    // Tasks which are not cancellable or already completed are reported as failures
+    let failures = ["task_failures", "node_failures"]
+        .iter()
+        .filter_map(|key| response_body[key].as_array())
+        .flatten();
+    for failure in failures {
+        let reason = match failure["reason"]["reason"].as_str() {
+            Some(reason) => reason.to_string(),
+            None => failure["reason"].to_string(),
+        };
+        print_error(reason);
+    }
+}
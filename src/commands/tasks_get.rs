@@ -0,0 +1,80 @@
+use anyhow::Result;
+use clap::Parser;
+use colored::Colorize;
+use serde_json::Value;
+use tabled::builder::Builder;
+use tabled::settings::object::Columns;
+use tabled::settings::{Modify, Width};
+
+use crate::application::Application;
+use crate::utils::output::{display, output_json, output_yaml, print_error, titled_rows, Output};
+
+use super::tasks::{format_progress, format_running_time, get_task_by_id};
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Id of the task (e.g. oTUltX4IQMOUUVeiohTt8A:12345)
+    task_id: String,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response_body = get_task_by_id(&args.task_id, &client).await?;
+
+    match args.output {
+        Output::Default => output_task(&args.task_id, &response_body),
+        Output::Json => output_json(&response_body, args.pretty)?,
+        Output::Yaml => output_yaml(&response_body)?,
+    }
+
+    Ok(())
+}
+
+fn output_task(task_id: &str, response_body: &Value) {
+    let task = &response_body["task"];
+    let completed = response_body["completed"] == true;
+
+    let state = match (completed, response_body.get("error")) {
+        (true, Some(_)) => "failed".red().bold(),
+        (true, None) => "completed".green(),
+        (false, _) if task["cancelled"] == true => "cancelling".yellow(),
+        (false, _) => "running".yellow(),
+    };
+
+    let mut rows = vec![
+        ("Action", display(&task["action"])),
+        ("State", state.to_string()),
+        ("Description", display(&task["description"])),
+        ("Node", display(&task["node"])),
+        ("Running time", format_running_time(task)),
+        ("Progress", format_progress(&task["status"])),
+        ("Cancellable", display(&task["cancellable"])),
+    ];
+    if let Some(parent_task_id) = task.get("parent_task_id") {
+        rows.push(("Parent task", display(parent_task_id)));
+    }
+
+    let mut builder = Builder::default();
+    for (name, value) in rows {
+        builder.push_record([name.bold().to_string(), value]);
+    }
+
+    let mut table = titled_rows(builder.build(), &format!("Task {}", task_id));
+    table.with(Modify::new(Columns::single(1)).with(Width::wrap(100)));
+
+    println!("{table}");
+
+    if let Some(error) = response_body.get("error") {
+        print_error(format!(
+            "{}: {}",
+            display(&error["type"]),
+            display(&error["reason"])
+        ));
+    }
+}
@@ -0,0 +1,118 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::params::GroupBy;
+use serde_json::Value;
+use tabled::builder::Builder;
+use tabled::settings::object::Columns;
+use tabled::settings::{Modify, Width};
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{display, output_json, output_yaml, titled_table, Output};
+
+use super::tasks::{format_progress, format_running_time};
+
+/// Tasks are sorted from the longest running
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Only lists the tasks matching these actions, comma separated (supports wildcards, e.g. *reindex*)
+    #[arg(short, long)]
+    actions: Option<String>,
+    /// Displays the description and the progress of the tasks
+    #[arg(short, long, default_value_t = false)]
+    detailed: bool,
+    /// Only lists parent tasks, hiding the subtasks of sliced or broadcast requests
+    #[arg(long, default_value_t = false)]
+    parent: bool,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let actions: Vec<&str> = match &args.actions {
+        Some(actions) => actions.split(',').map(str::trim).collect(),
+        None => vec![],
+    };
+
+    let client = application.get_http_client()?;
+    let tasks = client.tasks();
+    let mut request = tasks.list().group_by(GroupBy::None).detailed(args.detailed);
+    if !actions.is_empty() {
+        request = request.actions(&actions);
+    }
+
+    let response = request
+        .send()
+        .await
+        .context("Request error for listing tasks")?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            "Cannot list tasks".to_string(),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for tasks")?;
+
+    let mut tasks: Vec<&Value> = response_body["tasks"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|task| !args.parent || task.get("parent_task_id").is_none())
+        .collect();
+    tasks.sort_by_key(|task| std::cmp::Reverse(task["running_time_in_nanos"].as_u64()));
+
+    match args.output {
+        Output::Default => output_tasks(&tasks, args.detailed),
+        Output::Json => output_json(&tasks, args.pretty)?,
+        Output::Yaml => output_yaml(&tasks)?,
+    }
+
+    Ok(())
+}
+
+fn output_tasks(tasks: &[&Value], detailed: bool) {
+    let mut builder = Builder::default();
+    let mut header = vec!["task id", "action", "running time", "cancellable"];
+    if detailed {
+        header.extend(["progress", "description"]);
+    }
+    builder.set_header(header);
+
+    for task in tasks {
+        let task_id = format!("{}:{}", display(&task["node"]), display(&task["id"]));
+        let task_id = match task.get("parent_task_id") {
+            Some(_) => task_id,
+            None => task_id.bold().to_string(),
+        };
+
+        let mut record = vec![
+            task_id,
+            display(&task["action"]),
+            format_running_time(task),
+            display(&task["cancellable"]),
+        ];
+        if detailed {
+            record.push(format_progress(&task["status"]));
+            record.push(display(&task["description"]));
+        }
+        builder.push_record(record);
+    }
+
+    let mut table = titled_table(builder.build(), &format!("Tasks ({})", tasks.len()));
+    table.with(Modify::new(Columns::single(5)).with(Width::wrap(80)));
+
+    println!("{table}");
+}
@@ -0,0 +1,48 @@
+use anyhow::Result;
+use clap::Parser;
+use colored::Colorize;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_response;
+use crate::utils::output::Output;
+
+use super::reindex::parse_requests_per_second;
+use super::tasks::rethrottle_task;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Id of the reindex, update by query or delete by query task
+    task_id: String,
+    /// New throttling in sub-requests per second (`unlimited` or a number)
+    #[arg(value_parser = parse_requests_per_second, allow_negative_numbers = true)]
+    requests_per_second: i64,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = rethrottle_task(&args.task_id, &client, args.requests_per_second).await?;
+
+    let throttling = match args.requests_per_second {
+        -1 => "unlimited".to_string(),
+        requests_per_second => format!("{} requests per second", requests_per_second),
+    };
+
+    handle_response(
+        &args.output,
+        response,
+        format!(
+            "Task {} rethrottled to {}!",
+            args.task_id.bold(),
+            throttling
+        ),
+        format!("Task {} cannot be rethrottled!", args.task_id.bold()),
+        args.pretty,
+    )
+    .await
+}
@@ -0,0 +1,34 @@
+use anyhow::Result;
+use clap::Parser;
+
+use crate::application::Application;
+use crate::utils::output::Output;
+
+use super::reindex::{wait_for_task, PollArguments};
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Id of the task to wait for
+    task_id: String,
+    #[command(flatten)]
+    poll: PollArguments,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    wait_for_task(
+        &args.task_id,
+        &client,
+        "Task",
+        args.poll.interval(),
+        &args.output,
+        args.pretty,
+    )
+    .await
+}
@@ -0,0 +1,48 @@
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+use crate::application::Application;
+
+use super::templates_delete;
+use super::templates_get;
+use super::templates_list;
+use super::templates_put;
+use super::templates_simulate;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    #[command(subcommand)]
+    sub_commands: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    /// Lists the index templates, or the component templates
+    List(templates_list::Arguments),
+    /// Displays the definition of a template
+    Get(templates_get::Arguments),
+    /// Creates or replaces a template from a JSON definition
+    Put(templates_put::Arguments),
+    /// Deletes a template
+    Delete(templates_delete::Arguments),
+    /// Displays the settings, mappings and aliases an index would get from the templates
+    Simulate(templates_simulate::Arguments),
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    match &args.sub_commands {
+        Commands::List(args) => templates_list::handle_command(args, application).await,
+        Commands::Get(args) => templates_get::handle_command(args, application).await,
+        Commands::Put(args) => templates_put::handle_command(args, application).await,
+        Commands::Delete(args) => templates_delete::handle_command(args, application).await,
+        Commands::Simulate(args) => templates_simulate::handle_command(args, application).await,
+    }
+}
+
+/// Names of the composable index templates and component templates
+pub fn template_kind(component: bool) -> &'static str {
+    match component {
+        true => "Component template",
+        false => "Index template",
+    }
+}
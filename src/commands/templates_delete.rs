@@ -0,0 +1,58 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::cluster::ClusterDeleteComponentTemplateParts;
+use elasticsearch::indices::IndicesDeleteIndexTemplateParts;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_response;
+use crate::utils::output::Output;
+
+use super::templates::template_kind;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the template to delete
+    name: String,
+    /// Deletes a component template instead of an index template
+    #[arg(long, default_value_t = false)]
+    component: bool,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let kind = template_kind(args.component);
+
+    let client = application.get_http_client()?;
+    let response = match args.component {
+        true => {
+            client
+                .cluster()
+                .delete_component_template(ClusterDeleteComponentTemplateParts::Name(&args.name))
+                .send()
+                .await
+        }
+        false => {
+            client
+                .indices()
+                .delete_index_template(IndicesDeleteIndexTemplateParts::Name(&args.name))
+                .send()
+                .await
+        }
+    }
+    .context(format!("Request error for deleting {} {}", kind, args.name))?;
+
+    handle_response(
+        &args.output,
+        response,
+        format!("{} {} deleted successfully!", kind, args.name.bold()),
+        format!("{} {} cannot be deleted!", kind, args.name.bold()),
+        args.pretty,
+    )
+    .await
+}
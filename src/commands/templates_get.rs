@@ -0,0 +1,89 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::cluster::ClusterGetComponentTemplateParts;
+use elasticsearch::indices::IndicesGetIndexTemplateParts;
+use serde_json::Value;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{output_json, output_yaml, Output};
+
+use super::templates::template_kind;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the template (supports wildcards)
+    name: String,
+    /// Gets a component template instead of an index template
+    #[arg(long, default_value_t = false)]
+    component: bool,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let kind = template_kind(args.component);
+
+    let response = match args.component {
+        true => {
+            client
+                .cluster()
+                .get_component_template(ClusterGetComponentTemplateParts::Name(&[&args.name]))
+                .send()
+                .await
+        }
+        false => {
+            client
+                .indices()
+                .get_index_template(IndicesGetIndexTemplateParts::Name(&args.name))
+                .send()
+                .await
+        }
+    }
+    .context(format!("Request error for getting {} {}", kind, args.name))?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            format!("Cannot get {} {}", kind.to_lowercase(), args.name.bold()),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for templates")?;
+
+    // Definitions are keyed by the kind of template, next to their name
+    let (key, definition_key) = match args.component {
+        true => ("component_templates", "component_template"),
+        false => ("index_templates", "index_template"),
+    };
+    let templates = response_body[key].as_array().cloned().unwrap_or_default();
+
+    match args.output {
+        Output::Default => {
+            for template in &templates {
+                println!(
+                    "{} {}",
+                    kind.bold(),
+                    template["name"].as_str().unwrap_or_default().bold()
+                );
+                output_json(&template[definition_key], true)?;
+            }
+        }
+        Output::Json => output_json(&templates, args.pretty)?,
+        Output::Yaml => output_yaml(&templates)?,
+    }
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::cluster::ClusterGetComponentTemplateParts;
+use elasticsearch::indices::IndicesGetIndexTemplateParts;
+use elasticsearch::Elasticsearch;
+use serde_json::Value;
+use tabled::builder::Builder;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{display, output_json, output_yaml, print_table, Output};
+
+/// Templates are sorted by name, built-in templates included
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Only lists the templates matching this name (supports wildcards)
+    name: Option<String>,
+    /// Lists the component templates instead of the index templates
+    #[arg(long, default_value_t = false)]
+    component: bool,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let names: Vec<&str> = args.name.iter().map(String::as_str).collect();
+
+    let response = match args.component {
+        true => {
+            let parts = match names.is_empty() {
+                true => ClusterGetComponentTemplateParts::None,
+                false => ClusterGetComponentTemplateParts::Name(&names),
+            };
+            client.cluster().get_component_template(parts).send().await
+        }
+        false => {
+            let parts = match &args.name {
+                Some(name) => IndicesGetIndexTemplateParts::Name(name),
+                None => IndicesGetIndexTemplateParts::None,
+            };
+            client.indices().get_index_template(parts).send().await
+        }
+    }
+    .context("Request error for listing templates")?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            "Cannot list templates".to_string(),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for templates")?;
+
+    let key = match args.component {
+        true => "component_templates",
+        false => "index_templates",
+    };
+    let mut templates: Vec<&Value> = response_body[key]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .collect();
+    templates.sort_by_key(|template| template["name"].as_str());
+
+    match args.output {
+        Output::Default => match args.component {
+            true => {
+                let index_templates = get_index_templates(&client).await?;
+                output_component_templates(&templates, &index_templates);
+            }
+            false => output_index_templates(&templates),
+        },
+        Output::Json => output_json(&templates, args.pretty)?,
+        Output::Yaml => output_yaml(&templates)?,
+    }
+
+    Ok(())
+}
+
+fn output_index_templates(templates: &[&Value]) {
+    let mut builder = Builder::default();
+    builder.set_header([
+        "name",
+        "index patterns",
+        "priority",
+        "composed of",
+        "version",
+        "data stream",
+    ]);
+
+    for template in templates {
+        let index_template = &template["index_template"];
+        builder.push_record([
+            display(&template["name"]),
+            join(&index_template["index_patterns"]),
+            display(&index_template["priority"]),
+            join(&index_template["composed_of"]),
+            display(&index_template["version"]),
+            index_template.get("data_stream").is_some().to_string(),
+        ]);
+    }
+
+    print_table(builder.build(), "Index templates");
+}
+
+/// Displays which parts each component template defines, and the index templates using it
+fn output_component_templates(templates: &[&Value], index_templates: &[Value]) {
+    let mut builder = Builder::default();
+    builder.set_header(["name", "version", "defines", "used by"]);
+
+    for template in templates {
+        let name = display(&template["name"]);
+        let definition = &template["component_template"]["template"];
+        let defines: Vec<&str> = ["settings", "mappings", "aliases"]
+            .into_iter()
+            .filter(|part| definition.get(*part).is_some())
+            .collect();
+        let used_by: Vec<String> = index_templates
+            .iter()
+            .filter(|index_template| {
+                index_template["index_template"]["composed_of"]
+                    .as_array()
+                    .is_some_and(|composed_of| composed_of.contains(&Value::from(name.as_str())))
+            })
+            .map(|index_template| display(&index_template["name"]))
+            .collect();
+
+        builder.push_record([
+            name,
+            display(&template["component_template"]["version"]),
+            defines.join(", "),
+            match used_by.is_empty() {
+                true => "-".dimmed().to_string(),
+                false => used_by.join(", "),
+            },
+        ]);
+    }
+
+    print_table(builder.build(), "Component templates");
+}
+
+async fn get_index_templates(client: &Elasticsearch) -> Result<Vec<Value>> {
+    let response = client
+        .indices()
+        .get_index_template(IndicesGetIndexTemplateParts::None)
+        .send()
+        .await
+        .context("Request error for listing index templates")?;
+
+    if !response.status_code().is_success() {
+        bail!("Cannot list index templates");
+    }
+
+    let mut response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for index templates")?;
+
+    match response_body["index_templates"].take() {
+        Value::Array(templates) => Ok(templates),
+        _ => Ok(vec![]),
+    }
+}
+
+fn join(values: &Value) -> String {
+    match values.as_array() {
+        Some(values) if !values.is_empty() => values
+            .iter()
+            .map(display)
+            .collect::<Vec<String>>()
+            .join(", "),
+        _ => "-".to_string(),
+    }
+}
@@ -0,0 +1,70 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::cluster::ClusterPutComponentTemplateParts;
+use elasticsearch::indices::IndicesPutIndexTemplateParts;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_response;
+use crate::utils::input::read_json_input;
+use crate::utils::output::Output;
+
+use super::templates::template_kind;
+
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the template
+    name: String,
+    /// Definition of the template, inline JSON or path to a JSON file ("-" reads from stdin)
+    #[arg(short, long)]
+    file: String,
+    /// Puts a component template instead of an index template
+    #[arg(long, default_value_t = false)]
+    component: bool,
+    /// Fails if the template already exists instead of replacing it
+    #[arg(long, default_value_t = false)]
+    create: bool,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let definition = read_json_input(&args.file, "template")?;
+    let kind = template_kind(args.component);
+
+    let client = application.get_http_client()?;
+    let response = match args.component {
+        true => {
+            client
+                .cluster()
+                .put_component_template(ClusterPutComponentTemplateParts::Name(&args.name))
+                .create(args.create)
+                .body(definition)
+                .send()
+                .await
+        }
+        false => {
+            client
+                .indices()
+                .put_index_template(IndicesPutIndexTemplateParts::Name(&args.name))
+                .create(args.create)
+                .body(definition)
+                .send()
+                .await
+        }
+    }
+    .context(format!("Request error for putting {} {}", kind, args.name))?;
+
+    handle_response(
+        &args.output,
+        response,
+        format!("{} {} saved successfully!", kind, args.name.bold()),
+        format!("{} {} cannot be saved!", kind, args.name.bold()),
+        args.pretty,
+    )
+    .await
+}
@@ -0,0 +1,157 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::indices::IndicesSimulateIndexTemplateParts;
+use serde_json::Value;
+use tabled::builder::Builder;
+use tabled::settings::object::Columns;
+use tabled::settings::{Modify, Width};
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{
+    display, objects, output_json, output_yaml, print_error, titled_table, Output,
+};
+
+/// Nothing is created, the templates are only resolved for this index name
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Name of the index to simulate
+    index: String,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let client = application.get_http_client()?;
+    let response = client
+        .indices()
+        .simulate_index_template(IndicesSimulateIndexTemplateParts::Name(&args.index))
+        .send()
+        .await
+        .context(format!("Request error for simulating index {}", args.index))?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            format!("Cannot simulate index {}", args.index.bold()),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for index simulation")?;
+
+    match args.output {
+        Output::Default => output_simulation(&args.index, &response_body),
+        Output::Json => output_json(&response_body, args.pretty)?,
+        Output::Yaml => output_yaml(&response_body)?,
+    }
+
+    Ok(())
+}
+
+fn output_simulation(index: &str, response_body: &Value) {
+    let template = match response_body.get("template") {
+        Some(template) => template,
+        None => {
+            print_error(format!("No index template matches {}", index));
+            return;
+        }
+    };
+
+    let mut settings = Vec::new();
+    flatten_settings("", &template["settings"], &mut settings);
+    let mut builder = Builder::default();
+    builder.set_header(["setting", "value"]);
+    for (key, value) in settings {
+        builder.push_record([key, value]);
+    }
+    print_wrapped_table(builder, &format!("Settings of {}", index));
+
+    let mut fields = Vec::new();
+    collect_fields("", &template["mappings"]["properties"], &mut fields);
+    let mut builder = Builder::default();
+    builder.set_header(["field", "type"]);
+    for (field, field_type) in fields {
+        builder.push_record([field, field_type]);
+    }
+    print_wrapped_table(builder, &format!("Mappings of {}", index));
+
+    let aliases: Vec<&String> = objects(&template["aliases"])
+        .map(|(name, _)| name)
+        .collect();
+    if !aliases.is_empty() {
+        let aliases: Vec<&str> = aliases.iter().map(|alias| alias.as_str()).collect();
+        println!("{} {}", "Aliases:".bold(), aliases.join(", "));
+    }
+
+    // Templates matching the index with a lower priority are ignored
+    for overlapping in response_body["overlapping"]
+        .as_array()
+        .into_iter()
+        .flatten()
+    {
+        println!(
+            "{} {} also matches ({}) but has a lower priority",
+            "Overlapping:".yellow().bold(),
+            display(&overlapping["name"]),
+            overlapping["index_patterns"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(display)
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+    }
+}
+
+fn flatten_settings(prefix: &str, value: &Value, settings: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                let key = match prefix.is_empty() {
+                    true => key.to_owned(),
+                    false => format!("{}.{}", prefix, key),
+                };
+                flatten_settings(&key, value, settings);
+            }
+        }
+        Value::Null => {}
+        value => settings.push((prefix.to_string(), display(value))),
+    }
+}
+
+/// Lists the mapped fields with their type, including objects and multi-fields
+fn collect_fields(prefix: &str, properties: &Value, fields: &mut Vec<(String, String)>) {
+    for (name, mapping) in objects(properties) {
+        let path = match prefix.is_empty() {
+            true => name.to_owned(),
+            false => format!("{}.{}", prefix, name),
+        };
+        let field_type = match mapping["type"].as_str() {
+            Some(field_type) => field_type.to_string(),
+            None => "object".to_string(),
+        };
+        fields.push((path.clone(), field_type));
+
+        collect_fields(&path, &mapping["properties"], fields);
+        collect_fields(&path, &mapping["fields"], fields);
+    }
+}
+
+fn print_wrapped_table(builder: Builder, title: &str) {
+    let mut table = titled_table(builder.build(), title);
+    table.with(Modify::new(Columns::single(1)).with(Width::wrap(80)));
+
+    println!("{table}");
+}
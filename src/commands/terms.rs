@@ -0,0 +1,146 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use colored::Colorize;
+use elasticsearch::SearchParts;
+use serde_json::{json, Value};
+use tabled::builder::Builder;
+
+use crate::application::Application;
+use crate::utils::handle_response::handle_error_response;
+use crate::utils::output::{
+    format_bar, format_percent, output_json, output_yaml, print_table, Output,
+};
+
+use super::search::lucene_query;
+
+/// Width of the bars, in characters
+const BAR_WIDTH: usize = 30;
+
+/// Displays the most frequent values of a field
+#[derive(Debug, Parser)]
+pub struct Arguments {
+    /// Indices to aggregate, comma separated (supports wildcards)
+    index: String,
+    /// Field to aggregate, usually a keyword field
+    field: String,
+    /// Number of values to display
+    #[arg(short, long, default_value_t = 20)]
+    size: u32,
+    /// Query in the Lucene query string syntax
+    #[arg(short, long)]
+    query: Option<String>,
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = Output::Default)]
+    output: Output,
+    /// Pretty print JSON output
+    #[arg(short, long, default_value_t = false)]
+    pretty: bool,
+}
+
+pub async fn handle_command(args: &Arguments, application: &Application) -> Result<()> {
+    let mut body = json!({
+        "size": 0,
+        "track_total_hits": true,
+        "aggs": {
+            "terms": {
+                "terms": {
+                    "field": args.field,
+                    "size": args.size
+                }
+            }
+        }
+    });
+    if let Some(query) = &args.query {
+        body["query"] = lucene_query(query);
+    }
+
+    let client = application.get_http_client()?;
+    let indices: Vec<&str> = args.index.split(',').collect();
+    let response = client
+        .search(SearchParts::Index(&indices))
+        .body(body)
+        .send()
+        .await
+        .context(format!(
+            "Request error for aggregating {} of {}",
+            args.field, args.index
+        ))?;
+
+    if !response.status_code().is_success() {
+        return handle_error_response(
+            &args.output,
+            response,
+            format!(
+                "Cannot aggregate {} of {}",
+                args.field.bold(),
+                args.index.bold()
+            ),
+            args.pretty,
+        )
+        .await;
+    }
+
+    let response_body: Value = response
+        .json()
+        .await
+        .context("Cannot parse JSON response for terms aggregation")?;
+    let aggregation = &response_body["aggregations"]["terms"];
+
+    match args.output {
+        Output::Default => output_buckets(args, aggregation, &response_body["hits"]["total"]),
+        Output::Json => output_json(aggregation, args.pretty)?,
+        Output::Yaml => output_yaml(aggregation)?,
+    }
+
+    Ok(())
+}
+
+fn output_buckets(args: &Arguments, aggregation: &Value, total: &Value) {
+    let buckets = aggregation["buckets"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    let total = total["value"].as_u64().unwrap_or(0);
+    let max = buckets
+        .iter()
+        .filter_map(|bucket| bucket["doc_count"].as_u64())
+        .max()
+        .unwrap_or(0);
+
+    let mut builder = Builder::default();
+    builder.set_header(["value", "count", "share", ""]);
+
+    for bucket in &buckets {
+        let count = bucket["doc_count"].as_u64().unwrap_or(0);
+        // Keywords have a string key, numbers and booleans a formatted one
+        let key = match (&bucket["key_as_string"], &bucket["key"]) {
+            (Value::String(key), _) | (_, Value::String(key)) => key.to_owned(),
+            (_, key) => key.to_string(),
+        };
+
+        builder.push_record([
+            key,
+            count.to_string(),
+            format_percent(count, total),
+            format_bar(count as f64, max as f64, BAR_WIDTH)
+                .blue()
+                .to_string(),
+        ]);
+    }
+
+    let other = aggregation["sum_other_doc_count"].as_u64().unwrap_or(0);
+    if other > 0 {
+        builder.push_record([
+            "(other)".dimmed().to_string(),
+            other.to_string(),
+            format_percent(other, total),
+            String::new(),
+        ]);
+    }
+
+    print_table(
+        builder.build(),
+        &format!("Top {} values of {}", buckets.len(), args.field),
+    );
+    println!("{} documents in {}", total, args.index);
+}
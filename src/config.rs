@@ -82,7 +82,7 @@ impl Display for Protocol {
 
 fn read_config_file(path: &PathBuf) -> Result<Config> {
     let config_file =
-        File::open(&path).context(format!("Unable to open config file at {:?}", path))?;
+        File::open(path).context(format!("Unable to open config file at {:?}", path))?;
 
     let config: Config = serde_yaml::from_reader(config_file)
         .context(format!("Unable to deserialize config file at {:?}", path))?;
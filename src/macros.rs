@@ -2,6 +2,8 @@
 macro_rules! commands_enum {
     ($($module:ident),*) => (
         paste::paste! {
+            // Arguments are parsed once, their size does not matter
+            #[allow(clippy::large_enum_variant)]
             #[derive(Debug, Subcommand)]
             enum Commands {
                 $(
@@ -229,7 +229,7 @@ fn draw_header<B: Backend>(f: &mut Frame<B>, app: &mut UiApp, rect: Rect) {
     draw_infos(f, app, chunks[1]);
 }
 
-fn draw_infos<B: Backend>(f: &mut Frame<B>, app: &mut UiApp, rect: Rect) {
+fn draw_infos<B: Backend>(f: &mut Frame<B>, _app: &mut UiApp, rect: Rect) {
     let spans = vec![
         Spans::from(vec![
             Span::styled("Cluster: ", Style::default().fg(Color::LightBlue)),
@@ -249,7 +249,7 @@ fn draw_infos<B: Backend>(f: &mut Frame<B>, app: &mut UiApp, rect: Rect) {
 
 /// Render the tabs, one per screen
 fn draw_tabs<B: Backend>(f: &mut Frame<B>, app: &mut UiApp, rect: Rect) {
-    let screens = vec!["Indices", "Aliases", "Nodes"];
+    let screens = ["Indices", "Aliases", "Nodes"];
 
     let titles = screens
         .iter()
@@ -366,7 +366,7 @@ fn draw_indices_table<B: Backend>(f: &mut Frame<B>, app: &mut UiApp, rect: Rect)
 }
 
 /// Render an index table row
-fn draw_indices_table_row(app: &UiApp, index_name: &str) -> Row<'static> {
+fn draw_indices_table_row(_app: &UiApp, index_name: &str) -> Row<'static> {
     let cells: Vec<String> = vec![
         index_name.to_owned(),
         "".to_owned(),
@@ -2,7 +2,9 @@ use anyhow::Result;
 use elasticsearch::http::response::Response;
 use serde_json::Value;
 
-use super::output::{output_error_table, output_json, print_error, print_success, Output};
+use super::output::{
+    output_error_table, output_json, output_yaml, print_error, print_success, Output,
+};
 
 pub async fn handle_error_response(
     output: &Output,
@@ -20,6 +22,7 @@ pub async fn handle_error_response(
             output_error_table(reason, &status_code);
         }
         Output::Json => output_json(ex.error(), pretty)?,
+        Output::Yaml => output_yaml(ex.error())?,
     };
 
     Ok(())
@@ -42,6 +45,10 @@ pub async fn handle_response(
             let response_body: Value = response.json().await?;
             output_json(&response_body, pretty)?
         }
+        Output::Yaml => {
+            let response_body: Value = response.json().await?;
+            output_yaml(&response_body)?
+        }
     };
 
     Ok(())
@@ -0,0 +1,85 @@
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::sync::OnceLock;
+
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+use tokio::sync::{mpsc, Mutex};
+
+static STDIN_LINES: OnceLock<Mutex<mpsc::UnboundedReceiver<String>>> = OnceLock::new();
+
+/// Reads a JSON value given either inline or as a path to a JSON file ("-" reads from stdin)
+pub fn read_json_input(input: &str, kind: &str) -> Result<Value> {
+    let trimmed = input.trim_start();
+    if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        return serde_json::from_str(input).context(format!("Malformated inline {}", kind));
+    }
+
+    let content = read_text_input(input, kind)?;
+    serde_json::from_str(&content).context(format!("Malformated {} in {}", kind, input))
+}
+
+/// Reads the whole content of a file ("-" reads from stdin)
+pub fn read_text_input(path: &str, kind: &str) -> Result<String> {
+    let mut content = String::new();
+    match path {
+        "-" => io::stdin()
+            .read_to_string(&mut content)
+            .context(format!("Cannot read {} from stdin", kind))?,
+        _ => File::open(path)
+            .context(format!("Cannot open {} file at {}", kind, path))?
+            .read_to_string(&mut content)
+            .context(format!("Cannot read {} file at {}", kind, path))?,
+    };
+
+    Ok(content)
+}
+
+/// Asks a yes/no question on stdin, anything but `y` or `yes` means no
+pub fn confirm(question: &str) -> Result<bool> {
+    eprint!("{} [y/N] ", question);
+    io::stderr().flush().ok();
+
+    let mut answer = String::new();
+    io::stdin()
+        .read_line(&mut answer)
+        .context("Cannot read answer from stdin")?;
+
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Fails when an input read from stdin would leave no way to answer a confirmation
+pub fn check_confirmable_input(input: &str, option: &str, yes: bool) -> Result<()> {
+    if input == "-" && !yes {
+        bail!(
+            "{} cannot be read from stdin without --yes, stdin is needed for the confirmation",
+            option
+        );
+    }
+
+    Ok(())
+}
+
+/// Waits for the next line typed on stdin, without the lines typed before the call
+///
+/// A single thread reads stdin for all the callers, a read left pending by an abandoned call
+/// then answers the next call instead of being lost. Once started, it owns stdin.
+pub async fn read_stdin_line() -> Option<String> {
+    let lines = STDIN_LINES.get_or_init(|| {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        // Detached, so a pending read never prevents escli from exiting
+        std::thread::spawn(move || loop {
+            let mut line = String::new();
+            match io::stdin().read_line(&mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) if sender.send(line).is_err() => break,
+                Ok(_) => {}
+            }
+        });
+        Mutex::new(receiver)
+    });
+
+    let mut lines = lines.lock().await;
+    while lines.try_recv().is_ok() {}
+    lines.recv().await
+}
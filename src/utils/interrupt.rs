@@ -0,0 +1,44 @@
+use std::process;
+use std::sync::OnceLock;
+
+use tokio::sync::broadcast;
+
+/// Exit code of a process killed by SIGINT
+const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+static INTERRUPTS: OnceLock<broadcast::Sender<()>> = OnceLock::new();
+
+/// Ctrl-C presses received while listening, including the ones received while busy
+///
+/// Once escli listens for Ctrl-C, the process is no longer killed by it. A single listener
+/// forwards each press to the current subscribers, and exits the process when there is none.
+pub struct Interrupts {
+    receiver: broadcast::Receiver<()>,
+}
+
+impl Interrupts {
+    pub fn listen() -> Self {
+        let sender = INTERRUPTS.get_or_init(|| {
+            let (sender, _) = broadcast::channel(16);
+            let forwarder = sender.clone();
+            tokio::spawn(async move {
+                while tokio::signal::ctrl_c().await.is_ok() {
+                    if forwarder.send(()).is_err() {
+                        process::exit(INTERRUPTED_EXIT_CODE);
+                    }
+                }
+            });
+            sender
+        });
+
+        Interrupts {
+            receiver: sender.subscribe(),
+        }
+    }
+
+    /// Waits for the next Ctrl-C press
+    pub async fn recv(&mut self) {
+        // A lagging receiver missed some presses, which still means an interruption
+        self.receiver.recv().await.ok();
+    }
+}
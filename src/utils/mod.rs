@@ -1,2 +1,5 @@
 pub mod handle_response;
+pub mod input;
+pub mod interrupt;
 pub mod output;
+pub mod pattern;
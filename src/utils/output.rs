@@ -2,7 +2,11 @@ use anyhow::{Context, Result};
 use clap::ValueEnum;
 use colored::Colorize;
 use serde::Serialize;
-use tabled::{builder::Builder, settings::Style};
+use serde_json::{Map, Value};
+use tabled::builder::Builder;
+use tabled::settings::object::Rows;
+use tabled::settings::{Format, Modify, Panel, Style};
+use tabled::Table;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
 pub enum Output {
@@ -10,6 +14,8 @@ pub enum Output {
     Default,
     /// Displays output as JSON
     Json,
+    /// Displays output as YAML
+    Yaml,
 }
 
 pub fn output_json<T>(input: &T, pretty: bool) -> Result<()>
@@ -25,6 +31,16 @@ where
     Ok(())
 }
 
+pub fn output_yaml<T>(input: &T) -> Result<()>
+where
+    T: Serialize,
+{
+    let yaml = serde_yaml::to_string(&input).context("Cannot serialize as YAML")?;
+
+    print!("{yaml}");
+    Ok(())
+}
+
 pub fn output_error_table(reason: &str, status_code: &str) {
     let mut builder = Builder::default();
     builder
@@ -43,3 +59,81 @@ pub fn print_success(msg: String) {
 pub fn print_error(msg: String) {
     println!("{}", msg.red())
 }
+
+/// Formats a size in bytes with the units used by Elasticsearch (kb, mb, gb...)
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 6] = ["b", "kb", "mb", "gb", "tb", "pb"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{}{}", bytes, UNITS[0]),
+        _ => format!("{:.1}{}", value, UNITS[unit]),
+    }
+}
+
+/// Draws a horizontal bar proportional to the value, with a resolution of an eighth of a character
+pub fn format_bar(value: f64, max: f64, width: usize) -> String {
+    const EIGHTHS: [&str; 8] = ["", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+
+    if max <= 0.0 || value <= 0.0 {
+        return String::new();
+    }
+
+    let eighths = (value.min(max) / max * (width * 8) as f64).round() as usize;
+    // A non-zero value always gets a visible bar
+    let eighths = eighths.max(1);
+
+    format!("{}{}", "█".repeat(eighths / 8), EIGHTHS[eighths % 8])
+}
+
+pub fn format_percent(value: u64, total: u64) -> String {
+    match total {
+        0 => "-".to_string(),
+        _ => format!("{:.1}%", value as f64 * 100.0 / total as f64),
+    }
+}
+
+/// Displays a JSON value in a table cell, without the quotes of strings
+pub fn display(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.to_owned(),
+        Value::Null => "-".to_string(),
+        value => value.to_string(),
+    }
+}
+
+/// Iterates over the entries of a JSON object, nothing for other values
+pub fn objects(value: &Value) -> impl Iterator<Item = (&String, &Value)> {
+    value.as_object().into_iter().flat_map(Map::iter)
+}
+
+/// Adds a bold title above the table and makes its header row bold
+pub fn titled_table(mut table: Table, title: &str) -> Table {
+    let header_format = Format::content(|s| s.bold().to_string());
+
+    table
+        .with(Style::modern())
+        .with(Panel::header(title.bold().to_string()))
+        .with(Modify::new(Rows::single(1)).with(header_format));
+
+    table
+}
+
+/// Adds a bold title above a table of name and value rows, which has no header row
+pub fn titled_rows(mut table: Table, title: &str) -> Table {
+    table
+        .with(Style::modern())
+        .with(Panel::header(title.bold().to_string()));
+
+    table
+}
+
+pub fn print_table(table: Table, title: &str) {
+    println!("{}", titled_table(table, title));
+}
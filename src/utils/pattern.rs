@@ -0,0 +1,70 @@
+/// Matches a value against a comma separated list of patterns supporting `*` wildcards
+pub fn matches_patterns(patterns: &str, value: &str) -> bool {
+    patterns
+        .split(',')
+        .any(|pattern| matches_pattern(pattern.trim(), value))
+}
+
+fn matches_pattern(pattern: &str, value: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    let (first, last) = match parts.as_slice() {
+        [exact] => return *exact == value,
+        [first, .., last] => (*first, *last),
+        [] => return false,
+    };
+
+    let mut rest = match value.strip_prefix(first) {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(position) => rest = &rest[position + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_patterns_without_wildcards() {
+        assert!(matches_patterns("logs", "logs"));
+        assert!(!matches_patterns("logs", "logs-1"));
+        assert!(!matches_patterns("logs", "log"));
+    }
+
+    #[test]
+    fn matches_patterns_with_wildcards() {
+        assert!(matches_patterns("*", "logs"));
+        assert!(matches_patterns("*", ""));
+        assert!(matches_patterns("logs-*", "logs-2023"));
+        assert!(matches_patterns("logs-*", "logs-"));
+        assert!(matches_patterns("*-2023", "logs-2023"));
+        assert!(matches_patterns("l*s-*3", "logs-2023"));
+        assert!(!matches_patterns("logs-*", "metrics-2023"));
+        assert!(!matches_patterns("*-2023", "logs-2024"));
+    }
+
+    #[test]
+    fn matches_patterns_without_overlapping_parts() {
+        assert!(!matches_patterns("a*a", "a"));
+        assert!(matches_patterns("a*a", "aa"));
+        assert!(!matches_patterns("ab*b", "ab"));
+        assert!(!matches_patterns("a*b*b", "ab"));
+        assert!(matches_patterns("a*b*b", "abb"));
+        assert!(matches_patterns("a**a", "aa"));
+    }
+
+    #[test]
+    fn matches_patterns_in_a_list() {
+        assert!(matches_patterns("logs-*, metrics-*", "metrics-1"));
+        assert!(matches_patterns("logs,metrics", "logs"));
+        assert!(!matches_patterns("logs,metrics", "traces"));
+    }
+}